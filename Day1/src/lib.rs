@@ -0,0 +1,231 @@
+pub mod rebalance;
+pub mod stats;
+pub mod stream;
+
+use std::fmt;
+
+use common::diagnostic::Location;
+
+use stream::TopElves;
+
+/// Location of the puzzle input in the source tree, for benchmarks and tests.
+pub const PUZZLE_INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/puzzle_input.txt");
+/// The puzzle input embedded at build time, binaries fall back to it wherever they are installed.
+pub const PUZZLE_INPUT: &str = include_str!("../puzzle_input.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidCalories(Location),
+    CaloriesOverflow(Location),
+    TopSumOverflow(usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidCalories(location) => write!(f, "calories must be a positive number at {}", location),
+            ParseError::CaloriesOverflow(location) => write!(f, "total calories of an elf exceed {} at {}", u64::MAX, location),
+            ParseError::TopSumOverflow(count) => write!(f, "total calories of the top {} elves exceed {}", count, u64::MAX),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// An elf's inventory, `index` is the 1-based position of the elf in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub items: Vec<u64>,
+    pub total: u64,
+}
+
+/// Groups lines into inventories.
+///
+/// Lines may end in `\r\n` and carry surrounding whitespace.
+/// Any run of empty or whitespace only lines separates two elves.
+#[derive(Default)]
+pub(crate) struct InventoryParser {
+    elves_seen: usize,
+    items: Vec<u64>,
+    total: u64,
+}
+
+impl InventoryParser {
+    /// Feeds line `line_number`, returns the elf whose inventory the line completes.
+    pub(crate) fn feed(&mut self, line_number: usize, line: &str) -> Result<Option<Elf>, ParseError> {
+        let line = line.trim_end_matches(['\n', '\r']);
+        let calory_str = line.trim();
+        if calory_str.is_empty() {
+            return Ok(self.finish());
+        }
+        let location = || Location::of(line_number, line, calory_str);
+        let calories = calory_str.parse::<u64>().map_err(|_| ParseError::InvalidCalories(location()))?;
+        self.total = self.total.checked_add(calories).ok_or_else(|| ParseError::CaloriesOverflow(location()))?;
+        self.items.push(calories);
+        Ok(None)
+    }
+
+    /// Completes the inventory of the last elf at the end of the input.
+    pub(crate) fn finish(&mut self) -> Option<Elf> {
+        if self.items.is_empty() {
+            return None;
+        }
+        self.elves_seen += 1;
+        let elf = Elf { index: self.elves_seen, items: std::mem::take(&mut self.items), total: self.total };
+        self.total = 0;
+        Some(elf)
+    }
+}
+
+/// Parses the inventories in input order.
+pub fn parse_elves(contents: &str) -> Result<Vec<Elf>, ParseError> {
+    let mut parser = InventoryParser::default();
+    let mut elves = vec![];
+    for (i, line) in contents.lines().enumerate() {
+        elves.extend(parser.feed(i + 1, line)?);
+    }
+    elves.extend(parser.finish());
+    Ok(elves)
+}
+
+/// Sorts elves by their total calories, highest first. Elves with equal totals keep their input order.
+pub fn rank_elves(mut elves: Vec<Elf>) -> Vec<Elf> {
+    elves.sort_by(|a, b| b.total.cmp(&a.total).then(a.index.cmp(&b.index)));
+    elves
+}
+
+/// The `count` elves carrying the most calories, highest first.
+pub fn top_elves(contents: &str, count: usize) -> Result<Vec<Elf>, ParseError> {
+    let mut top = TopElves::new(count);
+    parse_elves(contents)?.into_iter().for_each(|elf| top.push(elf));
+    Ok(top.into_ranking())
+}
+
+/// Total calories of the first `count` elves of a ranking.
+pub fn sum_of_top(ranked: &[Elf], count: usize) -> Result<u64, ParseError> {
+    ranked.iter().take(count)
+        .try_fold(0u64, |sum, elf| sum.checked_add(elf.total))
+        .ok_or(ParseError::TopSumOverflow(count))
+}
+
+pub fn get_calories_per_elf(contents: &str) -> Result<Vec<u64>, ParseError> {
+    let mut vec_max_calories_per_elf: Vec<u64> = parse_elves(contents)?.iter().map(|elf| elf.total).collect();
+    vec_max_calories_per_elf.sort_unstable();
+    Ok(vec_max_calories_per_elf)
+}
+
+pub fn sum_top_calories(contents: &str, count: usize) -> Result<u64, ParseError> {
+    sum_of_top(&top_elves(contents, count)?, count)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn end_to_end_example() {
+        let contents = include_str!("../example.txt");
+        assert_eq!(sum_top_calories(contents, 3).unwrap(), 45000);
+    }
+
+    #[test]
+    fn end_to_end_example_part1() {
+        let contents = include_str!("../example.txt");
+        assert_eq!(sum_top_calories(contents, 1).unwrap(), 24000);
+    }
+
+    #[test]
+    fn parse_elves_keeps_index_and_items() {
+        let elves = parse_elves("1000\n2000\n\n4000").unwrap();
+        assert_eq!(elves, vec![
+            Elf { index: 1, items: vec![1000, 2000], total: 3000 },
+            Elf { index: 2, items: vec![4000], total: 4000 },
+        ]);
+    }
+
+    #[test]
+    fn top_elves_of_example() {
+        let contents = include_str!("../example.txt");
+        let top = top_elves(contents, 3).unwrap();
+        let ranking: Vec<(usize, u64)> = top.iter().map(|elf| (elf.index, elf.total)).collect();
+        assert_eq!(ranking, vec![(4, 24000), (3, 11000), (5, 10000)]);
+        assert_eq!(top[0].items, vec![7000, 8000, 9000]);
+    }
+
+    #[test]
+    fn rank_elves_keeps_input_order_for_equal_totals() {
+        let ranked = rank_elves(parse_elves("5\n\n7\n\n2\n3").unwrap());
+        let indices: Vec<usize> = ranked.iter().map(|elf| elf.index).collect();
+        assert_eq!(indices, vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn top_elves_with_more_than_available() {
+        assert_eq!(top_elves("100", 3).unwrap().len(), 1);
+        assert_eq!(sum_top_calories("100", 3).unwrap(), 100);
+    }
+
+    #[test]
+    fn invalid_calories_report_line_number() {
+        let contents = "1000\n2000\n\n3000\n4x00";
+        let error = get_calories_per_elf(contents).unwrap_err();
+        assert_eq!(error, ParseError::InvalidCalories(Location::new(5, 1, "4x00")));
+    }
+
+    #[test]
+    fn invalid_calories_after_multiple_empty_lines_report_line_number() {
+        let contents = "1000\n\n\n-5";
+        let error = get_calories_per_elf(contents).unwrap_err();
+        assert_eq!(error, ParseError::InvalidCalories(Location::new(4, 1, "-5")));
+    }
+
+    #[test]
+    fn runs_of_empty_lines_separate_two_elves() {
+        let elves = parse_elves("\n1000\n\n\n\n2000\n3000\n\n").unwrap();
+        assert_eq!(elves, vec![
+            Elf { index: 1, items: vec![1000], total: 1000 },
+            Elf { index: 2, items: vec![2000, 3000], total: 5000 },
+        ]);
+    }
+
+    #[test]
+    fn crlf_line_endings_are_accepted() {
+        let contents = include_str!("../example.txt").replace('\n', "\r\n");
+        assert_eq!(sum_top_calories(&contents, 3).unwrap(), 45000);
+        assert_eq!(parse_elves(&contents).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn whitespace_around_calories_is_ignored() {
+        let elves = parse_elves("1000  \n\t2000\n   \n3000").unwrap();
+        let totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+        assert_eq!(totals, vec![3000, 3000]);
+    }
+
+    #[test]
+    fn invalid_calories_report_column_after_whitespace() {
+        let error = parse_elves("1000\n  12a").unwrap_err();
+        assert_eq!(error, ParseError::InvalidCalories(Location::new(2, 3, "  12a")));
+    }
+
+    #[test]
+    fn calories_beyond_u32_are_summed() {
+        let contents = format!("{}\n{}", u32::MAX, u32::MAX);
+        assert_eq!(sum_top_calories(&contents, 1).unwrap(), 2 * u32::MAX as u64);
+    }
+
+    #[test]
+    fn overflowing_elf_total_is_an_error() {
+        let contents = format!("1\n\n{}\n1", u64::MAX);
+        let error = parse_elves(&contents).unwrap_err();
+        assert_eq!(error, ParseError::CaloriesOverflow(Location::new(4, 1, "1")));
+    }
+
+    #[test]
+    fn overflowing_top_sum_is_an_error() {
+        let contents = format!("{}\n\n{}", u64::MAX, 1);
+        assert_eq!(sum_top_calories(&contents, 1).unwrap(), u64::MAX);
+        assert_eq!(sum_top_calories(&contents, 2).unwrap_err(), ParseError::TopSumOverflow(2));
+    }
+}
@@ -1,17 +1,103 @@
-fn parse_and_unwrap(calory_str: &str) -> u32 {
-    calory_str.parse::<u32>().unwrap()
+use std::io::Read;
+
+use common::diagnostic::Report;
+use common::input::open_input;
+use day1::rebalance;
+use day1::stats::{histogram, statistics, write_csv};
+use day1::stream::top_elves_from_reader;
+use day1::{parse_elves, sum_of_top, Elf, PUZZLE_INPUT};
+
+const USAGE: &str = "usage: day1 [<input file|->] [--top <n>] [--stats | --csv | --rebalance]";
+
+#[derive(PartialEq, Eq)]
+enum Mode {
+    Ranking,
+    Stats,
+    Csv,
+    Rebalance,
+}
+
+struct Args {
+    input: Option<String>,
+    top: usize,
+    mode: Mode,
 }
 
-fn main() {
-    let contents = include_str!("../example.txt");
+fn parse_args(mut args: impl Iterator<Item=String>) -> Result<Args, String> {
+    let mut parsed = Args { input: None, top: 3, mode: Mode::Ranking };
+    while let Some(arg) = args.next() {
+        if arg == "--top" || arg == "-n" {
+            let value = args.next().ok_or("missing value for top")?;
+            parsed.top = value.parse().map_err(|_| format!("invalid top: '{}'", value))?;
+        } else if arg == "--stats" || arg == "--csv" || arg == "--rebalance" {
+            if parsed.mode != Mode::Ranking {
+                return Err(String::from("--stats, --csv and --rebalance are exclusive"));
+            }
+            parsed.mode = match arg.as_str() {
+                "--stats" => Mode::Stats,
+                "--csv" => Mode::Csv,
+                _ => Mode::Rebalance,
+            };
+        } else if parsed.input.is_none() {
+            parsed.input = Some(arg);
+        } else {
+            return Err(format!("unexpected argument '{}'", arg));
+        }
+    }
+    Ok(parsed)
+}
+
+fn print_stats(elves: &[Elf]) {
+    let Some(stats) = statistics(elves) else {
+        println!("No elves");
+        return;
+    };
+    print!("{}", stats);
+    let totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+    println!("Calories per elf:\n{}", histogram(&totals, 10, 40));
+}
+
+fn print_plan(elves: &[Elf]) -> Result<(), Report> {
+    let plan = rebalance::plan(elves)?;
+    let totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+    println!("Spread before: {}", rebalance::spread(&totals));
+    println!("Spread after: {} ({})", plan.spread(), if plan.optimal { "optimal" } else { "greedy" });
+    println!("{} transfer(s):", plan.transfers.len());
+    for transfer in &plan.transfers {
+        println!("  {:>6} calories from elf {:>4} to elf {:>4}", transfer.calories, transfer.from, transfer.to);
+    }
+    Ok(())
+}
 
-    let mut vec_max_calories_per_elf: Vec<u32> = contents.split("\n\n")
-        .map(|calories_per_elf| calories_per_elf
-            .lines()
-            .map(|calory_str| parse_and_unwrap(calory_str))
-            .sum())
-        .collect();
-    vec_max_calories_per_elf.sort_unstable();
+fn main() -> Result<(), Report> {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            std::process::exit(1);
+        }
+    };
+    let mut reader = open_input(args.input.as_deref(), PUZZLE_INPUT)?;
+    if args.mode != Mode::Ranking {
+        // statistics need every elf, not only the top ones
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+        let elves = parse_elves(&contents)?;
+        match args.mode {
+            Mode::Csv => write_csv(&elves, std::io::stdout().lock())?,
+            Mode::Rebalance => print_plan(&elves)?,
+            _ => print_stats(&elves),
+        }
+        return Ok(());
+    }
+    let ranked = top_elves_from_reader(reader, args.top.max(3))?;
 
-    println!("Top 3 calories:\n{}", vec_max_calories_per_elf.iter().rev().take(3).sum::<u32>());
+    println!("Most calories:\n{}", sum_of_top(&ranked, 1)?);
+    println!("Top 3 calories:\n{}", sum_of_top(&ranked, 3)?);
+    println!("Top {} elves:", args.top);
+    for elf in ranked.iter().take(args.top) {
+        let items: Vec<String> = elf.items.iter().map(|c| c.to_string()).collect();
+        println!("  elf {:>4}: {:>6} calories ({})", elf.index, elf.total, items.join(", "));
+    }
+    Ok(())
 }
@@ -0,0 +1,317 @@
+//! Redistribution of whole food items so that every elf carries about the same calories.
+//!
+//! This is multiway number partitioning: the items are split into as many bins as there are elves.
+//! Small inputs are solved exactly by branch and bound, larger ones greedily by handing the largest
+//! remaining item to the lightest bin.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::Elf;
+
+/// Inputs with at most this many items are partitioned exactly.
+pub const EXACT_ITEM_LIMIT: usize = 16;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RebalanceError {
+    /// The calories of all elves together exceed `u64::MAX`, so some bin could too.
+    TotalOverflow,
+}
+
+impl fmt::Display for RebalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RebalanceError::TotalOverflow => write!(f, "total calories of all elves exceed {}", u64::MAX),
+        }
+    }
+}
+
+impl std::error::Error for RebalanceError {}
+
+/// Hands a food item from elf `from` to elf `to`, both 1-based indices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transfer {
+    pub calories: u64,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// Totals per elf after the transfers, in input order.
+    pub totals: Vec<u64>,
+    pub transfers: Vec<Transfer>,
+    /// Whether no other distribution has a smaller spread.
+    pub optimal: bool,
+}
+
+impl Plan {
+    pub fn spread(&self) -> u64 {
+        spread(&self.totals)
+    }
+}
+
+/// Difference between the largest and the smallest total.
+pub fn spread(totals: &[u64]) -> u64 {
+    match (totals.iter().max(), totals.iter().min()) {
+        (Some(max), Some(min)) => max - min,
+        _ => 0,
+    }
+}
+
+/// Largest item to the lightest bin, `items` sorted in descending order.
+fn greedy(items: &[u64], bins: usize) -> Vec<usize> {
+    let mut loads = vec![0u64; bins];
+    items.iter()
+        .map(|item| {
+            let lightest = (0..bins).min_by_key(|b| loads[*b]).unwrap();
+            loads[lightest] += item;
+            lightest
+        })
+        .collect()
+}
+
+struct Search<'a> {
+    items: &'a [u64],
+    /// Sum of the items from index i on.
+    remaining: Vec<u64>,
+    /// Largest possible value of the smallest load.
+    average: u64,
+    loads: Vec<u64>,
+    assignment: Vec<usize>,
+    best_spread: u64,
+    best_assignment: Vec<usize>,
+}
+
+impl Search<'_> {
+    fn branch(&mut self, i: usize) {
+        let max = *self.loads.iter().max().unwrap();
+        let min = *self.loads.iter().min().unwrap();
+        // loads only grow: the largest stays at least `max`, the smallest ends at most `min + remaining`
+        let lower_bound = max.saturating_sub((min + self.remaining[i]).min(self.average));
+        if lower_bound >= self.best_spread {
+            return;
+        }
+        if i == self.items.len() {
+            self.best_spread = max - min;
+            self.best_assignment = self.assignment.clone();
+            return;
+        }
+        let mut order: Vec<usize> = (0..self.loads.len()).collect();
+        order.sort_by_key(|b| self.loads[*b]);
+        order.dedup_by_key(|b| self.loads[*b]); // bins of equal load lead to the same distributions
+        for b in order {
+            self.loads[b] += self.items[i];
+            self.assignment[i] = b;
+            self.branch(i + 1);
+            self.loads[b] -= self.items[i];
+        }
+    }
+}
+
+/// Smallest spread by branch and bound, starting from the `greedy` assignment.
+fn exact(items: &[u64], bins: usize, greedy: Vec<usize>) -> Vec<usize> {
+    let mut loads = vec![0u64; bins];
+    for (item, b) in items.iter().zip(&greedy) {
+        loads[*b] += item;
+    }
+    let mut remaining = vec![0u64; items.len() + 1];
+    for i in (0..items.len()).rev() {
+        remaining[i] = remaining[i + 1] + items[i];
+    }
+    let mut search = Search {
+        items,
+        average: remaining[0] / bins as u64,
+        remaining,
+        loads: vec![0; bins],
+        assignment: vec![0; items.len()],
+        best_spread: spread(&loads),
+        best_assignment: greedy,
+    };
+    search.branch(0);
+    search.best_assignment
+}
+
+/// Splits two sorted lists into their common items and the items only in `a` and only in `b`.
+fn split_common(a: &[u64], b: &[u64]) -> (usize, Vec<u64>, Vec<u64>) {
+    let (mut i, mut j, mut common) = (0, 0, 0);
+    let (mut only_a, mut only_b) = (vec![], vec![]);
+    while i < a.len() || j < b.len() {
+        if j == b.len() || (i < a.len() && a[i] < b[j]) {
+            only_a.push(a[i]);
+            i += 1;
+        } else if i == a.len() || b[j] < a[i] {
+            only_b.push(b[j]);
+            j += 1;
+        } else {
+            common += 1;
+            i += 1;
+            j += 1;
+        }
+    }
+    (common, only_a, only_b)
+}
+
+/// Plans item transfers that even out the totals of `elves`.
+///
+/// Each bin goes to the elf that already holds most of its items, which keeps the transfers few
+/// but does not guarantee the fewest.
+pub fn plan(elves: &[Elf]) -> Result<Plan, RebalanceError> {
+    elves.iter().try_fold(0u64, |sum, elf| sum.checked_add(elf.total)).ok_or(RebalanceError::TotalOverflow)?;
+    if elves.is_empty() {
+        return Ok(Plan { totals: vec![], transfers: vec![], optimal: true });
+    }
+    let mut items: Vec<u64> = elves.iter().flat_map(|elf| elf.items.iter().copied()).collect();
+    items.sort_unstable_by(|a, b| b.cmp(a));
+
+    let assignment = greedy(&items, elves.len());
+    let searched = items.len() <= EXACT_ITEM_LIMIT;
+    let assignment = if searched { exact(&items, elves.len(), assignment) } else { assignment };
+    let mut bins = vec![vec![]; elves.len()];
+    for (item, b) in items.iter().zip(&assignment) {
+        bins[*b].push(*item);
+    }
+    for bin in &mut bins {
+        bin.sort_unstable();
+    }
+
+    // assign bins to elves by decreasing number of items the elf can keep
+    let inventories: Vec<Vec<u64>> = elves.iter()
+        .map(|elf| {
+            let mut items = elf.items.clone();
+            items.sort_unstable();
+            items
+        })
+        .collect();
+    let mut pairs = vec![];
+    for (e, inventory) in inventories.iter().enumerate() {
+        for (b, bin) in bins.iter().enumerate() {
+            pairs.push((split_common(inventory, bin).0, e, b));
+        }
+    }
+    pairs.sort_by(|x, y| y.0.cmp(&x.0).then((x.1, x.2).cmp(&(y.1, y.2))));
+    let mut bin_of_elf = vec![None; elves.len()];
+    let mut bin_taken = vec![false; elves.len()];
+    for (_, e, b) in pairs {
+        if bin_of_elf[e].is_none() && !bin_taken[b] {
+            bin_of_elf[e] = Some(b);
+            bin_taken[b] = true;
+        }
+    }
+
+    let mut surplus: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut needed = vec![];
+    for (e, inventory) in inventories.iter().enumerate() {
+        let (_, give, take) = split_common(inventory, &bins[bin_of_elf[e].unwrap()]);
+        for calories in give {
+            surplus.entry(calories).or_default().push(elves[e].index);
+        }
+        needed.push(take);
+    }
+    let mut transfers = vec![];
+    for (e, take) in needed.into_iter().enumerate() {
+        for calories in take {
+            let from = surplus.get_mut(&calories).and_then(|sources| sources.pop()).unwrap();
+            transfers.push(Transfer { calories, from, to: elves[e].index });
+        }
+    }
+
+    let totals: Vec<u64> = bin_of_elf.iter().map(|b| bins[b.unwrap()].iter().sum()).collect();
+    let optimal = searched || spread(&totals) == 0;
+    Ok(Plan { totals, transfers, optimal })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse_elves;
+
+    /// Totals after applying the transfers of `plan` to `elves`.
+    fn apply(elves: &[Elf], plan: &Plan) -> Vec<u64> {
+        let mut inventories: Vec<Vec<u64>> = elves.iter().map(|elf| elf.items.clone()).collect();
+        for transfer in &plan.transfers {
+            let from = &mut inventories[transfer.from - 1];
+            let position = from.iter().position(|c| *c == transfer.calories).expect("elf holds the item");
+            from.remove(position);
+            inventories[transfer.to - 1].push(transfer.calories);
+        }
+        inventories.iter().map(|items| items.iter().sum()).collect()
+    }
+
+    #[test]
+    fn example_balances_perfectly() {
+        let elves = parse_elves(include_str!("../example.txt")).unwrap();
+        let plan = super::plan(&elves).unwrap();
+        assert_eq!(plan.totals, vec![11000; 5]);
+        assert_eq!(plan.spread(), 0);
+        assert!(plan.optimal);
+        assert_eq!(apply(&elves, &plan), plan.totals);
+    }
+
+    #[test]
+    fn greedy_is_not_always_optimal() {
+        let items = [3, 3, 2, 2, 2];
+        let loads = |assignment: &[usize]| {
+            let mut loads = vec![0; 2];
+            for (item, b) in items.iter().zip(assignment) {
+                loads[*b] += item;
+            }
+            loads
+        };
+        let greedy_assignment = greedy(&items, 2);
+        assert_eq!(spread(&loads(&greedy_assignment)), 2);
+        assert_eq!(spread(&loads(&exact(&items, 2, greedy_assignment))), 0);
+    }
+
+    #[test]
+    fn exact_plan_moves_items_to_reach_smallest_spread() {
+        let elves = parse_elves("3\n3\n\n2\n2\n2").unwrap();
+        let plan = super::plan(&elves).unwrap();
+        assert_eq!(plan.spread(), 0);
+        assert!(plan.transfers.is_empty());
+
+        let elves = parse_elves("3\n2\n2\n\n3\n2").unwrap();
+        let plan = super::plan(&elves).unwrap();
+        assert_eq!(plan.totals, vec![6, 6]);
+        assert_eq!(plan.transfers.len(), 2);
+        assert_eq!(apply(&elves, &plan), plan.totals);
+    }
+
+    #[test]
+    fn balanced_elves_keep_their_items() {
+        let elves = parse_elves("5\n\n2\n3\n\n1\n4").unwrap();
+        let plan = super::plan(&elves).unwrap();
+        assert_eq!(plan.totals, vec![5, 5, 5]);
+        assert_eq!(plan.transfers, vec![]);
+    }
+
+    #[test]
+    fn indivisible_items_leave_a_spread() {
+        let elves = parse_elves("10\n1\n\n1").unwrap();
+        let plan = super::plan(&elves).unwrap();
+        assert_eq!(plan.totals, vec![10, 2]);
+        assert_eq!(plan.transfers, vec![Transfer { calories: 1, from: 1, to: 2 }]);
+        assert!(plan.optimal);
+    }
+
+    #[test]
+    fn large_inputs_are_planned_greedily() {
+        let contents: Vec<String> = (1..=30).map(|i| (i * 37 % 101).to_string()).collect();
+        let elves = parse_elves(&contents.chunks(3).map(|c| c.join("\n")).collect::<Vec<_>>().join("\n\n")).unwrap();
+        let plan = super::plan(&elves).unwrap();
+        assert!(plan.spread() < spread(&elves.iter().map(|elf| elf.total).collect::<Vec<_>>()));
+        assert_eq!(apply(&elves, &plan), plan.totals);
+    }
+
+    #[test]
+    fn plan_of_nothing() {
+        assert_eq!(plan(&[]).unwrap(), Plan { totals: vec![], transfers: vec![], optimal: true });
+    }
+
+    #[test]
+    fn plan_rejects_overflowing_sum() {
+        let elves = parse_elves(&format!("{}\n\n1", u64::MAX)).unwrap();
+        assert_eq!(plan(&elves), Err(RebalanceError::TotalOverflow));
+        assert_eq!(RebalanceError::TotalOverflow.to_string(), "total calories of all elves exceed 18446744073709551615");
+    }
+}
@@ -0,0 +1,187 @@
+//! Distribution of the calories carried by the elves.
+
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::{rank_elves, Elf};
+
+/// Summary of a list of values. Percentiles use the nearest rank method.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub p90: u64,
+    pub p99: u64,
+    pub min: u64,
+    pub max: u64,
+}
+
+fn percentile(sorted: &[u64], p: usize) -> u64 {
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/// Summarizes `values`, `None` if there are no values.
+pub fn summarize(values: &[u64]) -> Option<Summary> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let n = sorted.len();
+    let median = if n % 2 == 1 {
+        sorted[n / 2] as f64
+    } else {
+        (sorted[n / 2 - 1] as f64 + sorted[n / 2] as f64) / 2.0
+    };
+    Some(Summary {
+        count: n,
+        mean: sorted.iter().map(|v| *v as f64).sum::<f64>() / n as f64,
+        median,
+        p90: percentile(&sorted, 90),
+        p99: percentile(&sorted, 99),
+        min: sorted[0],
+        max: sorted[n - 1],
+    })
+}
+
+/// Summaries of the totals per elf, of the single food items and of the number of items per elf.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+    pub totals: Summary,
+    pub items: Summary,
+    pub items_per_elf: Summary,
+}
+
+pub fn statistics(elves: &[Elf]) -> Option<Statistics> {
+    let totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+    let items: Vec<u64> = elves.iter().flat_map(|elf| elf.items.iter().copied()).collect();
+    let items_per_elf: Vec<u64> = elves.iter().map(|elf| elf.items.len() as u64).collect();
+    Some(Statistics {
+        totals: summarize(&totals)?,
+        items: summarize(&items)?,
+        items_per_elf: summarize(&items_per_elf)?,
+    })
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<14} {:>7} {:>10} {:>10} {:>8} {:>8} {:>8} {:>8}", "", "count", "mean", "median", "p90", "p99", "min", "max")?;
+        let rows = [("calories/elf", &self.totals), ("calories/item", &self.items), ("items/elf", &self.items_per_elf)];
+        for (name, s) in rows {
+            writeln!(f, "{:<14} {:>7} {:>10.1} {:>10.1} {:>8} {:>8} {:>8} {:>8}",
+                     name, s.count, s.mean, s.median, s.p90, s.p99, s.min, s.max)?;
+        }
+        Ok(())
+    }
+}
+
+/// ASCII histogram of `values` in `buckets` buckets of equal width.
+/// The longest bar is `width` characters long.
+pub fn histogram(values: &[u64], buckets: usize, width: usize) -> String {
+    let (Some(min), Some(max)) = (values.iter().min(), values.iter().max()) else {
+        return String::new();
+    };
+    let buckets = buckets.max(1);
+    // a single bucket over the whole u64 range is u64::MAX wide, its largest value lands one past the end
+    let bucket_width = ((max - min) / buckets as u64).saturating_add(1);
+    let mut counts = vec![0usize; buckets];
+    for v in values {
+        counts[(((v - min) / bucket_width) as usize).min(buckets - 1)] += 1;
+    }
+    let highest = *counts.iter().max().unwrap();
+
+    let mut lines = vec![];
+    for (i, count) in counts.iter().enumerate() {
+        // totals may be close to u64::MAX, the last bucket ends at the largest value
+        let low = min.saturating_add((i as u64).saturating_mul(bucket_width));
+        let mut high = low.saturating_add(bucket_width - 1);
+        if i == buckets - 1 && low <= *max {
+            high = *max;
+        }
+        let bar = "#".repeat((count * width).div_ceil(highest));
+        lines.push(format!("{:>8} - {:>8} | {:<width$} {}", low, high, bar, count, width = width));
+    }
+    lines.join("\n")
+}
+
+/// Writes one row per elf in input order: index, rank, number of items and total calories.
+pub fn write_csv<W: Write>(elves: &[Elf], mut writer: W) -> io::Result<()> {
+    let mut ranks = vec![0; elves.len() + 1];
+    for (rank, elf) in rank_elves(elves.to_vec()).iter().enumerate() {
+        ranks[elf.index] = rank + 1;
+    }
+    writeln!(writer, "elf,rank,items,total")?;
+    for elf in elves {
+        writeln!(writer, "{},{},{},{}", elf.index, ranks[elf.index], elf.items.len(), elf.total)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse_elves;
+
+    #[test]
+    fn summarize_odd_number_of_values() {
+        let summary = summarize(&[6000, 4000, 11000, 24000, 10000]).unwrap();
+        assert_eq!(summary.count, 5);
+        assert_eq!(summary.mean, 11000.0);
+        assert_eq!(summary.median, 10000.0);
+        assert_eq!((summary.min, summary.max), (4000, 24000));
+        assert_eq!((summary.p90, summary.p99), (24000, 24000));
+    }
+
+    #[test]
+    fn summarize_even_number_of_values_averages_median() {
+        let summary = summarize(&[1, 2, 3, 10]).unwrap();
+        assert_eq!(summary.median, 2.5);
+        assert_eq!(summary.mean, 4.0);
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let values: Vec<u64> = (1..=200).collect();
+        let summary = summarize(&values).unwrap();
+        assert_eq!((summary.p90, summary.p99), (180, 198));
+        assert_eq!(summarize(&[7]).unwrap().p90, 7);
+    }
+
+    #[test]
+    fn summarize_nothing() {
+        assert_eq!(summarize(&[]), None);
+        assert_eq!(statistics(&[]), None);
+    }
+
+    #[test]
+    fn statistics_of_example() {
+        let elves = parse_elves(include_str!("../example.txt")).unwrap();
+        let stats = statistics(&elves).unwrap();
+        assert_eq!(stats.totals.max, 24000);
+        assert_eq!(stats.items.count, 10);
+        assert_eq!(stats.items.min, 1000);
+        assert_eq!((stats.items_per_elf.min, stats.items_per_elf.max), (1, 3));
+        assert_eq!(stats.items_per_elf.mean, 2.0);
+    }
+
+    #[test]
+    fn histogram_bars_scale_with_counts() {
+        let lines = histogram(&[0, 1, 2, 3, 9], 2, 4);
+        assert_eq!(lines, "       0 -        4 | #### 4\n       5 -        9 | #    1");
+        assert_eq!(histogram(&[], 2, 4), "");
+        assert_eq!(histogram(&[5, 5], 3, 2), "       5 -        5 | ## 2\n       6 -        6 |    0\n       7 -        7 |    0");
+        let lines = histogram(&[u64::MAX, 1], 2, 1);
+        assert_eq!(lines, "       1 - 9223372036854775808 | # 1\n9223372036854775809 - 18446744073709551615 | # 1");
+        assert_eq!(histogram(&[0, u64::MAX], 1, 2), "       0 - 18446744073709551615 | ## 2");
+    }
+
+    #[test]
+    fn csv_has_one_row_per_elf() {
+        let elves = parse_elves("1000\n2000\n\n4000\n\n500").unwrap();
+        let mut csv = vec![];
+        write_csv(&elves, &mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "elf,rank,items,total\n1,2,2,3000\n2,1,1,4000\n3,3,1,500\n");
+    }
+}
@@ -0,0 +1,162 @@
+//! Top elves of inventories that are read line by line instead of being loaded as a whole.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::{Elf, InventoryParser, ParseError};
+
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "could not read inventory: {}", e),
+            ReadError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(e: ParseError) -> Self {
+        ReadError::Parse(e)
+    }
+}
+
+/// Orders elves by rank, the best elf is the greatest.
+/// Equal totals rank the elf that comes first in the input higher.
+struct Ranked(Elf);
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Ranked {}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total.cmp(&other.0.total).then(other.0.index.cmp(&self.0.index))
+    }
+}
+
+/// Keeps the `count` best elves seen so far in a min-heap, so memory does not grow with the input.
+pub struct TopElves {
+    count: usize,
+    heap: BinaryHeap<Reverse<Ranked>>,
+}
+
+impl TopElves {
+    pub fn new(count: usize) -> Self {
+        Self { count, heap: BinaryHeap::with_capacity(count + 1) }
+    }
+
+    pub fn push(&mut self, elf: Elf) {
+        if self.count == 0 {
+            return;
+        }
+        let candidate = Ranked(elf);
+        if self.heap.len() == self.count {
+            if candidate <= self.heap.peek().unwrap().0 {
+                return;
+            }
+            self.heap.pop();
+        }
+        self.heap.push(Reverse(candidate));
+    }
+
+    /// The collected elves, best first.
+    pub fn into_ranking(self) -> Vec<Elf> {
+        self.heap.into_sorted_vec().into_iter().map(|Reverse(Ranked(elf))| elf).collect()
+    }
+}
+
+/// The `count` elves carrying the most calories, best first, read from `reader` one line at a time.
+/// Memory use only depends on `count` and the size of a single inventory.
+/// Any run of empty lines separates two elves.
+pub fn top_elves_from_reader<R: BufRead>(mut reader: R, count: usize) -> Result<Vec<Elf>, ReadError> {
+    let mut top = TopElves::new(count);
+    let mut parser = InventoryParser::default();
+    let mut line = String::new();
+    let mut line_number = 0;
+    while reader.read_line(&mut line)? > 0 {
+        line_number += 1;
+        if let Some(elf) = parser.feed(line_number, &line)? {
+            top.push(elf);
+        }
+        line.clear();
+    }
+    if let Some(elf) = parser.finish() {
+        top.push(elf);
+    }
+    Ok(top.into_ranking())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse_elves, rank_elves};
+
+    fn ranking(elves: &[Elf]) -> Vec<(usize, u64)> {
+        elves.iter().map(|elf| (elf.index, elf.total)).collect()
+    }
+
+    #[test]
+    fn top_elves_from_reader_of_example() {
+        let contents = include_str!("../example.txt");
+        let top = top_elves_from_reader(contents.as_bytes(), 3).unwrap();
+        assert_eq!(ranking(&top), vec![(4, 24000), (3, 11000), (5, 10000)]);
+        assert_eq!(top[1].items, vec![5000, 6000]);
+    }
+
+    #[test]
+    fn top_elves_from_reader_matches_full_ranking() {
+        let contents = std::fs::read_to_string(crate::PUZZLE_INPUT_PATH).unwrap();
+        let ranked = rank_elves(parse_elves(&contents).unwrap());
+        for count in [0, 1, 3, 10, ranked.len() + 5] {
+            let top = top_elves_from_reader(contents.as_bytes(), count).unwrap();
+            assert_eq!(top, ranked.iter().take(count).cloned().collect::<Vec<_>>(), "top {}", count);
+        }
+    }
+
+    #[test]
+    fn top_elves_keeps_input_order_for_equal_totals() {
+        let top = top_elves_from_reader("5\n\n7\n\n5\n\n5".as_bytes(), 2).unwrap();
+        assert_eq!(ranking(&top), vec![(2, 7), (1, 5)]);
+    }
+
+    #[test]
+    fn top_elves_from_reader_accepts_crlf_and_empty_line_runs() {
+        let top = top_elves_from_reader("1\r\n2\r\n\r\n\r\n5\r\n".as_bytes(), 3).unwrap();
+        assert_eq!(ranking(&top), vec![(2, 5), (1, 3)]);
+    }
+
+    #[test]
+    fn top_elves_from_reader_reports_line_of_invalid_calories() {
+        let error = top_elves_from_reader("1000\n\n20x0\n".as_bytes(), 1).unwrap_err();
+        match error {
+            ReadError::Parse(ParseError::InvalidCalories(location)) => assert_eq!(location.line, 3),
+            other => panic!("unexpected error {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::path::{Path, PathBuf};
+
+/// The answers file checked in at the root of the workspace, embedded at build time
+/// so that `verify` works wherever the binary is installed.
+pub const ANSWERS: &str = include_str!("../../answers.txt");
+
+/// The inputs the embedded answers refer to, by their path relative to the answers file.
+const BUNDLED_INPUTS: [(&str, &str); 18] = [
+    ("Day1/puzzle_input.txt", day1::PUZZLE_INPUT),
+    ("Day1/example.txt", include_str!("../../Day1/example.txt")),
+    ("day2/puzzle_input.txt", day2::PUZZLE_INPUT),
+    ("day2/example.txt", include_str!("../../day2/example.txt")),
+    ("day3/puzzle_input.txt", day3::PUZZLE_INPUT),
+    ("day3/example.txt", include_str!("../../day3/example.txt")),
+    ("day4/puzzle_input.txt", day4::PUZZLE_INPUT),
+    ("day4/example.txt", include_str!("../../day4/example.txt")),
+    ("day5a/puzzle_input.txt", day5a::PUZZLE_INPUT),
+    ("day5a/example.txt", include_str!("../../day5a/example.txt")),
+    ("day6a/puzzle_input.txt", day6a::PUZZLE_INPUT),
+    ("day7b/puzzle_input.txt", day7b::PUZZLE_INPUT),
+    ("day7b/example.txt", include_str!("../../day7b/example.txt")),
+    ("day8ab/puzzle_input.txt", day8ab::PUZZLE_INPUT),
+    ("day8ab/example.txt", include_str!("../../day8ab/example.txt")),
+    ("day9b/puzzle_input.txt", day9b::PUZZLE_INPUT),
+    ("day9b/example.txt", include_str!("../../day9b/example.txt")),
+    ("day9b/example2.txt", include_str!("../../day9b/example2.txt")),
+];
+
+/// One line of the answers file: the answer a part has to produce for an input file.
+/// The input path is relative to the directory of the answers file.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExpectedAnswer {
+    pub day: u32,
+    pub part: u32,
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+fn parse_line(line_number: usize, line: &str) -> Result<ExpectedAnswer, String> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() != 4 {
+        return Err(format!("line {}: expected 'day part input answer', got '{}'", line_number, line));
+    }
+    let day = fields[0].parse::<u32>().map_err(|_| format!("line {}: invalid day '{}'", line_number, fields[0]))?;
+    let part = fields[1].parse::<u32>().map_err(|_| format!("line {}: invalid part '{}'", line_number, fields[1]))?;
+    Ok(ExpectedAnswer { day, part, input: PathBuf::from(fields[2]), answer: fields[3].to_string() })
+}
+
+/// The embedded contents of an input of the embedded answers file.
+pub fn bundled_input(input: &Path) -> Option<&'static str> {
+    BUNDLED_INPUTS.iter().find(|(path, _)| Path::new(path) == input).map(|(_, contents)| *contents)
+}
+
+/// Parses an answers file.
+/// Empty lines and lines starting with `#` are ignored.
+pub fn parse_answers(contents: &str) -> Result<Vec<ExpectedAnswer>, String> {
+    contents.lines().enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| parse_line(i + 1, line))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_answers_skips_comments_and_empty_lines() {
+        let contents = "# day part input answer\n\n7  1  day7b/example.txt  95437\n5 2 day5a/example.txt MCD\n";
+        let answers = parse_answers(contents).unwrap();
+        assert_eq!(answers, vec![
+            ExpectedAnswer { day: 7, part: 1, input: PathBuf::from("day7b/example.txt"), answer: String::from("95437") },
+            ExpectedAnswer { day: 5, part: 2, input: PathBuf::from("day5a/example.txt"), answer: String::from("MCD") },
+        ]);
+    }
+
+    #[test]
+    fn parse_answers_reports_line_of_malformed_entry() {
+        let contents = "# comment\n7 1 day7b/example.txt\n";
+        let error = parse_answers(contents).unwrap_err();
+        assert!(error.starts_with("line 2:"), "{}", error);
+    }
+
+    #[test]
+    fn parse_answers_rejects_invalid_part() {
+        let error = parse_answers("7 b day7b/example.txt 95437").unwrap_err();
+        assert_eq!(error, "line 1: invalid part 'b'");
+    }
+
+    #[test]
+    fn embedded_answers_have_bundled_inputs() {
+        let base_dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+        for answer in parse_answers(ANSWERS).unwrap() {
+            let bundled = bundled_input(&answer.input).unwrap_or_else(|| panic!("{} is not bundled", answer.input.display()));
+            assert_eq!(bundled, std::fs::read_to_string(base_dir.join(&answer.input)).unwrap());
+        }
+    }
+}
@@ -0,0 +1,234 @@
+mod answers;
+#[cfg(test)]
+mod readme_examples;
+mod report;
+mod solutions;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use common::input::{checksum, read_input};
+use common::solution::{DaySolver, PARTS};
+use report::{Format, PartReport};
+
+const USAGE: &str = "usage: aoc run [<day>] [--part <1|2>] [--input <file|-> | --input-dir <dir>] [--format <text|json>]
+       aoc verify [--answers <file>]";
+
+struct RunArgs {
+    day: Option<u32>,
+    part: Option<u32>,
+    input: Option<String>,
+    input_dir: Option<PathBuf>,
+    format: Format,
+}
+
+fn parse_number(value: Option<String>, what: &str) -> Result<u32, String> {
+    let value = value.ok_or(format!("missing value for {}", what))?;
+    value.parse::<u32>().map_err(|_| format!("invalid {}: '{}'", what, value))
+}
+
+fn parse_run_args(mut args: impl Iterator<Item=String>) -> Result<RunArgs, String> {
+    let mut run_args = RunArgs { day: None, part: None, input: None, input_dir: None, format: Format::Text };
+    while let Some(arg) = args.next() {
+        if arg == "--part" || arg == "-p" {
+            run_args.part = Some(parse_number(args.next(), "part")?);
+        } else if arg == "--input" || arg == "-i" {
+            run_args.input = Some(args.next().ok_or("missing value for input")?);
+        } else if arg == "--input-dir" {
+            run_args.input_dir = Some(PathBuf::from(args.next().ok_or("missing value for input-dir")?));
+        } else if arg == "--format" || arg == "-f" {
+            let value = args.next().ok_or("missing value for format")?;
+            run_args.format = Format::parse(&value).ok_or(format!("format must be text or json, got '{}'", value))?;
+        } else if run_args.day.is_none() {
+            run_args.day = Some(parse_number(Some(arg), "day")?);
+        } else {
+            return Err(format!("unexpected argument '{}'", arg));
+        }
+    }
+
+    if let Some(day) = run_args.day {
+        if solutions::find_solver(day).is_none() {
+            return Err(format!("no solver for day {}", day));
+        }
+    }
+    if let Some(part) = run_args.part {
+        if !PARTS.contains(&part) {
+            return Err(format!("part must be 1 or 2, got {}", part));
+        }
+    }
+    if run_args.input.is_some() && run_args.day.is_none() {
+        return Err(String::from("--input needs a day, use --input-dir to run all days"));
+    }
+    if run_args.input.is_some() && run_args.input_dir.is_some() {
+        return Err(String::from("--input and --input-dir can not be combined"));
+    }
+    Ok(run_args)
+}
+
+/// Picks the input for one day: an explicit `--input`, `day<N>.txt` inside `--input-dir`,
+/// or the puzzle input embedded in the day's crate.
+fn load_input(run_args: &RunArgs, solver: &DaySolver) -> std::io::Result<String> {
+    if let Some(input_dir) = &run_args.input_dir {
+        // read the file directly, a path that is not valid UTF-8 must not fall back to the bundled input
+        return std::fs::read_to_string(input_dir.join(format!("day{}.txt", solver.day)));
+    }
+    read_input(run_args.input.as_deref(), solver.puzzle_input)
+}
+
+fn run(run_args: &RunArgs) -> Result<(), String> {
+    let selected_solvers: Vec<&DaySolver> = solutions::SOLVERS.iter()
+        .filter(|s| run_args.day.is_none_or(|d| d == s.day))
+        .collect();
+    let selected_parts = run_args.part.map_or(PARTS.to_vec(), |p| vec![p]);
+
+    let mut failed_parts = 0;
+    let mut reports = vec![];
+    for solver in selected_solvers {
+        let input = load_input(run_args, solver)
+            .map_err(|e| format!("could not read input for day {}: {}", solver.day, e))?;
+        let input_checksum = checksum(&input);
+
+        let results = solver.solve_parts_timed(&selected_parts, &input);
+        for (part, result) in selected_parts.iter().zip(results) {
+            if result.is_err() {
+                failed_parts += 1;
+            }
+            match (run_args.format, &result) {
+                (Format::Json, _) => reports.push(PartReport::new(solver.day, *part, input_checksum.clone(), &result)),
+                (Format::Text, Ok(timed)) => println!("day {} part {}: {}", solver.day, part, timed.answer),
+                (Format::Text, Err(e)) => eprintln!("day {} part {}: {}", solver.day, part, e),
+            }
+        }
+    }
+
+    if run_args.format == Format::Json {
+        println!("{}", serde_json::to_string_pretty(&reports).map_err(|e| e.to_string())?);
+    }
+
+    if failed_parts > 0 {
+        return Err(format!("{} part(s) failed", failed_parts));
+    }
+    Ok(())
+}
+
+/// The answers file to verify, `None` for the embedded one.
+fn parse_verify_args(mut args: impl Iterator<Item=String>) -> Result<Option<PathBuf>, String> {
+    let mut answers_path = None;
+    while let Some(arg) = args.next() {
+        if arg == "--answers" || arg == "-a" {
+            answers_path = Some(PathBuf::from(args.next().ok_or("missing value for answers")?));
+        } else {
+            return Err(format!("unexpected argument '{}'", arg));
+        }
+    }
+    Ok(answers_path)
+}
+
+/// Runs every entry of the answers file and compares the result with the expected answer.
+/// Without a path the embedded answers file is checked against the bundled inputs.
+fn verify(answers_path: Option<&Path>) -> Result<(), String> {
+    let (name, contents) = match answers_path {
+        Some(path) => {
+            let contents = std::fs::read_to_string(path)
+                .map_err(|e| format!("could not read answers file {}: {}", path.display(), e))?;
+            (path.display().to_string(), contents)
+        }
+        None => (String::from("embedded answers"), String::from(answers::ANSWERS)),
+    };
+    let expected_answers = answers::parse_answers(&contents)
+        .map_err(|message| format!("{}: {}", name, message))?;
+
+    let mut failed = 0;
+    for expected in &expected_answers {
+        let label = format!("day {} part {} ({})", expected.day, expected.part, expected.input.display());
+        let solver = solutions::find_solver(expected.day).ok_or(format!("{}: no solver for day {}", label, expected.day))?;
+        let input = match answers_path {
+            Some(path) => std::fs::read_to_string(path.parent().unwrap_or(Path::new("")).join(&expected.input))
+                .map_err(|e| format!("{}: could not read input: {}", label, e))?,
+            None => answers::bundled_input(&expected.input)
+                .ok_or(format!("{}: input is not bundled", label))?
+                .to_string(),
+        };
+
+        match solver.solve(expected.part, &input) {
+            Ok(answer) if answer.to_string() == expected.answer => println!("ok       {}: {}", label, answer),
+            Ok(answer) => {
+                println!("mismatch {}: expected {}, got {}", label, expected.answer, answer);
+                failed += 1;
+            }
+            Err(e) => {
+                println!("error    {}: {}", label, e);
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} of {} answer(s) did not match", failed, expected_answers.len()));
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+
+    let result = match args.next().as_deref() {
+        Some("run") => parse_run_args(args)
+            .map_err(|message| format!("{}\n{}", message, USAGE))
+            .and_then(|run_args| run(&run_args)),
+        Some("verify") => parse_verify_args(args)
+            .map_err(|message| format!("{}\n{}", message, USAGE))
+            .and_then(|answers_path| verify(answers_path.as_deref())),
+        _ => Err(String::from(USAGE)),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A fresh directory for one test, unique across concurrent test runs.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn embedded_answers_match() {
+        assert_eq!(verify(None), Ok(()));
+    }
+
+    #[test]
+    fn checked_in_answers_file_matches() {
+        let answers_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"));
+        assert_eq!(verify(Some(answers_path)), Ok(()));
+    }
+
+    #[test]
+    fn verify_reports_mismatch() {
+        let dir = temp_dir("verify_reports_mismatch");
+        std::fs::write(dir.join("day6.txt"), "mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+        std::fs::write(dir.join("answers.txt"), "6 1 day6.txt 7\n6 2 day6.txt 20\n").unwrap();
+        let result = verify(Some(&dir.join("answers.txt")));
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result, Err(String::from("1 of 2 answer(s) did not match")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn input_dir_that_is_not_utf8_is_not_replaced_by_the_bundled_input() {
+        use std::os::unix::ffi::OsStrExt;
+        let input_dir = PathBuf::from(std::ffi::OsStr::from_bytes(b"/nonexistent/\xff"));
+        let run_args = RunArgs { day: Some(6), part: None, input: None, input_dir: Some(input_dir), format: Format::Text };
+        assert!(load_input(&run_args, solutions::find_solver(6).unwrap()).is_err());
+    }
+}
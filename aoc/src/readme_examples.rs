@@ -0,0 +1,189 @@
+//! Example tests taken from the puzzle descriptions in the `README.md` of each day, the name is matched
+//! case-insensitively as some days have a `readme.md`.
+//!
+//! The input of an example is the indented block following a paragraph ending in "For example:",
+//! its answer is the last emphasized value (`` _`95437`_ ``) before the next example or part.
+//! Bullet points of the form `` *   `input`: ... _`answer`_ `` are examples on their own.
+
+use std::path::{Path, PathBuf};
+
+use crate::solutions;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub answer: String,
+}
+
+fn parse_day(heading: &str) -> Option<u32> {
+    let rest = heading.strip_prefix("# Day ")?;
+    rest.split(':').next()?.trim().parse().ok()
+}
+
+/// Paragraphs like "For example:" or "Here's a larger example:" precede the input of an example.
+/// Other mentions such as "in the example above:" precede diagrams.
+fn introduces_example(line: &str) -> bool {
+    let line = line.to_lowercase();
+    (line.contains("for example") || line.contains("larger example")) && line.trim_end().ends_with(':')
+}
+
+/// Last emphasized value of a line, e.g. `95437` in ``the sum is _`95437`_ (94853 + 584)``.
+fn last_emphasized(line: &str) -> Option<&str> {
+    let start = line.rfind("_`")? + 2;
+    let len = line[start..].find("`_")?;
+    Some(&line[start..start + len])
+}
+
+/// Splits a bullet point like `` *   `bvwbjplbgvbhsrlpgdmjqwftvncz`: first marker after character _`5`_ ``.
+fn inline_example(line: &str) -> Option<(&str, &str)> {
+    let rest = line.strip_prefix("*   `")?;
+    let (input, rest) = rest.split_once("`:")?;
+    Some((input, last_emphasized(rest)?))
+}
+
+/// Indented block following line `i`, without the indentation.
+fn indented_block_after(lines: &[&str], i: usize) -> Option<String> {
+    let block: Vec<&str> = lines[i + 1..].iter()
+        .skip_while(|l| l.is_empty())
+        .take_while(|l| l.starts_with("    "))
+        .map(|l| &l[4..])
+        .collect();
+    if block.is_empty() {
+        return None;
+    }
+    Some(block.join("\n").trim_end().to_string())
+}
+
+pub fn extract_examples(readme: &str) -> Vec<Example> {
+    let lines: Vec<&str> = readme.lines().collect();
+    let Some(day) = lines.iter().find_map(|l| parse_day(l)) else {
+        return vec![];
+    };
+
+    let mut examples = vec![];
+    let mut part = 1;
+    let mut input: Option<String> = None;
+    let mut answer: Option<String> = None;
+    for (i, line) in lines.iter().enumerate() {
+        let starts_new_example = line.starts_with("### Part Two") || introduces_example(line);
+        if starts_new_example {
+            if let (Some(input), Some(answer)) = (&input, answer.take()) {
+                examples.push(Example { day, part, input: input.clone(), answer });
+            }
+        }
+
+        if line.starts_with("### Part Two") {
+            part = 2;
+        } else if introduces_example(line) {
+            input = indented_block_after(&lines, i).or(input);
+        } else if let Some((inline_input, inline_answer)) = inline_example(line) {
+            examples.push(Example { day, part, input: inline_input.to_string(), answer: inline_answer.to_string() });
+        } else if !line.starts_with("    ") {
+            if let Some(emphasized) = last_emphasized(line) {
+                answer = Some(emphasized.to_string());
+            }
+        }
+    }
+    if let (Some(input), Some(answer)) = (input, answer) {
+        examples.push(Example { day, part, input, answer });
+    }
+    examples
+}
+
+/// The readme of a crate directory, whatever the case of its name.
+fn readme_in(dir: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(dir).ok()?
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.is_file() && path.file_name().unwrap().eq_ignore_ascii_case("readme.md"))
+}
+
+fn workspace_readmes() -> Vec<PathBuf> {
+    let workspace_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let mut readmes: Vec<PathBuf> = std::fs::read_dir(workspace_dir).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .filter_map(|dir| readme_in(&dir))
+        .collect();
+    readmes.sort();
+    readmes
+}
+
+#[cfg(test)]
+mod test {
+    use common::solution::Answer;
+
+    use super::*;
+
+    #[test]
+    fn extract_block_example_with_answers_of_both_parts() {
+        let readme = "# Day 7: No Space Left On Device\n\n### Part One\n\nFor example:\n\n    $ cd /\n    $ ls\n    \n    dir a\n\n\
+            The sum is _`95437`_ (94853 + 584).\n\n### Part Two\n\nThe disk has _`70000000`_, you free up _`24933642`_.\n";
+        let examples = extract_examples(readme);
+        let input = String::from("$ cd /\n$ ls\n\ndir a");
+        assert_eq!(examples, vec![
+            Example { day: 7, part: 1, input: input.clone(), answer: String::from("95437") },
+            Example { day: 7, part: 2, input, answer: String::from("24933642") },
+        ]);
+    }
+
+    #[test]
+    fn extract_larger_example_in_second_part() {
+        let readme = "# Day 9: Rope Bridge\n\nFor example:\n\n    R 4\n\nIt visits _`13`_ positions.\n\n### Part Two\n\n\
+            It only visits _`1`_ position.\n\nHere's a larger example:\n\n    R 5\n    U 8\n\nIt visits _`36`_ positions.\n";
+        let examples = extract_examples(readme);
+        assert_eq!(examples.len(), 3);
+        assert_eq!((examples[1].part, examples[1].input.as_str(), examples[1].answer.as_str()), (2, "R 4", "1"));
+        assert_eq!((examples[2].part, examples[2].input.as_str(), examples[2].answer.as_str()), (2, "R 5\nU 8", "36"));
+    }
+
+    #[test]
+    fn extract_inline_examples_from_bullet_points() {
+        let readme = "# Day 6: Tuning Trouble\n\n*   `bvwbjplbgvbhsrlpgdmjqwftvncz`: first marker after character _`5`_\n";
+        let examples = extract_examples(readme);
+        assert_eq!(examples, vec![
+            Example { day: 6, part: 1, input: String::from("bvwbjplbgvbhsrlpgdmjqwftvncz"), answer: String::from("5") },
+        ]);
+    }
+
+    #[test]
+    fn readmes_are_found_whatever_their_case() {
+        let readmes = workspace_readmes();
+        assert!(readmes.iter().any(|path| path.ends_with("Day1/readme.md")), "{:?}", readmes);
+        assert!(readmes.iter().any(|path| path.ends_with("day3/README.md")), "{:?}", readmes);
+    }
+
+    /// Examples checked and skipped for parts without a solution, per day.
+    #[derive(Default)]
+    struct Coverage {
+        checked: usize,
+        skipped: usize,
+    }
+
+    #[test]
+    fn readme_examples_match() {
+        let mut coverage: Vec<Coverage> = solutions::SOLVERS.iter().map(|_| Coverage::default()).collect();
+        let mut failures = vec![];
+        for readme in workspace_readmes() {
+            let contents = std::fs::read_to_string(&readme).unwrap();
+            for example in extract_examples(&contents) {
+                let solver = solutions::find_solver(example.day).unwrap();
+                let day = &mut coverage[solutions::SOLVERS.iter().position(|s| s.day == example.day).unwrap()];
+                match solver.solve(example.part, &example.input) {
+                    Ok(Answer::NotImplemented) => day.skipped += 1,
+                    Ok(answer) if answer.to_string() == example.answer => day.checked += 1,
+                    result => failures.push(format!("{} day {} part {}: expected {}, got {:?}",
+                        readme.display(), example.day, example.part, example.answer, result)),
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+        // a day may only go unchecked if its readme describes parts that are not solved, like day 4
+        let unchecked: Vec<u32> = solutions::SOLVERS.iter().zip(&coverage)
+            .filter(|(_, day)| day.checked == 0 && day.skipped == 0)
+            .map(|(solver, _)| solver.day)
+            .collect();
+        assert!(unchecked.is_empty(), "no readme examples checked for days {:?}", unchecked);
+    }
+}
@@ -0,0 +1,110 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use common::solution::{Answer, TimedSolveResult};
+
+/// Output format of `aoc run`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Result of one part in the JSON output.
+/// Numbers stay numbers, text answers are strings and parts that are not implemented are `null`.
+#[derive(Debug, Serialize)]
+pub struct PartReport {
+    pub day: u32,
+    pub part: u32,
+    pub answer: Value,
+    pub error: Option<String>,
+    pub parse_time_ns: Option<u128>,
+    pub solve_time_ns: Option<u128>,
+    pub input_checksum: String,
+}
+
+fn answer_to_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(n) => Value::from(*n),
+        Answer::Text(s) => Value::from(s.as_str()),
+        Answer::NotImplemented => Value::Null,
+    }
+}
+
+impl PartReport {
+    pub fn new(day: u32, part: u32, input_checksum: String, result: &TimedSolveResult) -> Self {
+        let mut report = PartReport {
+            day,
+            part,
+            answer: Value::Null,
+            error: None,
+            parse_time_ns: None,
+            solve_time_ns: None,
+            input_checksum,
+        };
+        match result {
+            Ok(timed) => {
+                report.answer = answer_to_json(&timed.answer);
+                report.parse_time_ns = Some(timed.parse_time.as_nanos());
+                report.solve_time_ns = Some(timed.solve_time.as_nanos());
+            }
+            Err(e) => report.error = Some(e.to_string()),
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use common::solution::TimedAnswer;
+
+    use super::*;
+
+    #[test]
+    fn part_report_of_answer() {
+        let result = Ok(TimedAnswer {
+            answer: Answer::from(95437),
+            parse_time: Duration::from_nanos(1500),
+            solve_time: Duration::from_nanos(20),
+        });
+        let report = PartReport::new(7, 1, String::from("cbf29ce484222325"), &result);
+        assert_eq!(serde_json::to_string(&report).unwrap(),
+                   r#"{"day":7,"part":1,"answer":95437,"error":null,"parse_time_ns":1500,"solve_time_ns":20,"input_checksum":"cbf29ce484222325"}"#);
+    }
+
+    #[test]
+    fn part_report_of_text_and_missing_answers() {
+        let timed = |answer| Ok(TimedAnswer { answer, parse_time: Duration::ZERO, solve_time: Duration::ZERO });
+        let report = PartReport::new(5, 1, String::new(), &timed(Answer::from(String::from("CMZ"))));
+        assert_eq!(report.answer, Value::from("CMZ"));
+        let report = PartReport::new(1, 1, String::new(), &timed(Answer::NotImplemented));
+        assert_eq!(report.answer, Value::Null);
+    }
+
+    #[test]
+    fn part_report_of_error() {
+        let report = PartReport::new(4, 2, String::new(), &Err("invalid section id".into()));
+        assert_eq!(report.answer, Value::Null);
+        assert_eq!(report.error.as_deref(), Some("invalid section id"));
+        assert_eq!(report.parse_time_ns, None);
+    }
+
+    #[test]
+    fn format_parse() {
+        assert_eq!(Format::parse("json"), Some(Format::Json));
+        assert_eq!(Format::parse("text"), Some(Format::Text));
+        assert_eq!(Format::parse("yaml"), None);
+    }
+}
@@ -0,0 +1,24 @@
+use common::solution::{Answer, Solution};
+use day1::Elf;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const PUZZLE_INPUT: &'static str = day1::PUZZLE_INPUT;
+
+    type Parsed = Vec<Elf>;
+    type Error = day1::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        Ok(day1::rank_elves(day1::parse_elves(input)?))
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(day1::sum_of_top(parsed, 1)?.into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(day1::sum_of_top(parsed, 3)?.into())
+    }
+}
@@ -0,0 +1,24 @@
+use common::solution::{Answer, Solution};
+use day2::{GuideEntry, Strategy};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    const PUZZLE_INPUT: &'static str = day2::PUZZLE_INPUT;
+
+    type Parsed = Vec<GuideEntry>;
+    type Error = day2::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        day2::parse_guide(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(day2::total_score(parsed, Strategy::Shapes).into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(day2::total_score(parsed, Strategy::Outcomes).into())
+    }
+}
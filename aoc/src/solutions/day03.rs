@@ -0,0 +1,24 @@
+use common::solution::{Answer, Solution};
+use day3::Rucksack;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    const PUZZLE_INPUT: &'static str = day3::PUZZLE_INPUT;
+
+    type Parsed = Vec<Rucksack>;
+    type Error = day3::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        day3::parse_rucksacks(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(day3::compartment_priority_sum(parsed)?.into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(day3::badge_priority_sum(parsed, day3::GROUP_SIZE)?.into())
+    }
+}
@@ -0,0 +1,20 @@
+use common::solution::{Answer, Solution};
+use day4::ElvesPair;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const PUZZLE_INPUT: &'static str = day4::PUZZLE_INPUT;
+
+    type Parsed = Vec<ElvesPair>;
+    type Error = day4::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        day4::parse_elves_pairs(input)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(parsed.iter().filter(|ep| ep.check_for_any_overlap()).count().into())
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt;
+
+use common::solution::{Answer, Solution};
+
+/// The crane models of both parts live in separate crates, each with its own error type.
+#[derive(Debug)]
+pub enum Error {
+    CrateMover9000(day5a::ParseError),
+    CrateMover9001(day5b::ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::CrateMover9000(e) => write!(f, "{}", e),
+            Error::CrateMover9001(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    const PUZZLE_INPUT: &'static str = day5a::PUZZLE_INPUT;
+
+    type Parsed = (day5a::Stacks, day5b::Stacks);
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        let stacks_9000 = day5a::Stacks::new(input).map_err(Error::CrateMover9000)?;
+        let stacks_9001 = day5b::Stacks::new(input).map_err(Error::CrateMover9001)?;
+        Ok((stacks_9000, stacks_9001))
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        let mut stacks = parsed.0.clone();
+        stacks.apply_moves().map_err(Error::CrateMover9000)?;
+        Ok(stacks.get_top_crates().into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        let mut stacks = parsed.1.clone();
+        stacks.apply_moves().map_err(Error::CrateMover9001)?;
+        Ok(stacks.get_top_crates().into())
+    }
+}
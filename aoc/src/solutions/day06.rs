@@ -0,0 +1,43 @@
+use std::fmt;
+
+use common::solution::{Answer, Solution};
+
+/// The marker lengths of both parts live in separate crates, each with its own error type.
+#[derive(Debug)]
+pub enum Error {
+    StartOfPacket(day6a::ParseError),
+    StartOfMessage(day6b::ParseError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::StartOfPacket(e) => write!(f, "{}", e),
+            Error::StartOfMessage(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const PUZZLE_INPUT: &'static str = day6a::PUZZLE_INPUT;
+
+    type Parsed = String;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        Ok(input.to_string())
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(day6a::find_start_signal_position(parsed).map_err(Error::StartOfPacket)?.into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(day6b::find_start_signal_position(parsed).map_err(Error::StartOfMessage)?.into())
+    }
+}
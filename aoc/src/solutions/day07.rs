@@ -0,0 +1,25 @@
+use common::solution::{Answer, Solution};
+use day7b::Directory;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const PUZZLE_INPUT: &'static str = day7b::PUZZLE_INPUT;
+
+    type Parsed = Directory;
+    type Error = day7b::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        day7b::get_directories(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(day7b::add_up_file_size_if_larger_than(parsed).into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        let required_space = day7b::get_required_space(parsed);
+        Ok(day7b::size_of_smallest_dir_above(required_space, parsed).into())
+    }
+}
@@ -0,0 +1,24 @@
+use common::solution::{Answer, Solution};
+use day8ab::Forest;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    const PUZZLE_INPUT: &'static str = day8ab::PUZZLE_INPUT;
+
+    type Parsed = Forest;
+    type Error = day8ab::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        Forest::new(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(parsed.get_visible_trees().into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(parsed.calculate_scenic_score_max().into())
+    }
+}
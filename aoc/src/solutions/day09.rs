@@ -0,0 +1,24 @@
+use common::solution::{Answer, Solution};
+use grid::Vector;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const PUZZLE_INPUT: &'static str = day9b::PUZZLE_INPUT;
+
+    type Parsed = Vec<Vector>;
+    type Error = day9b::ParseError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+        day9b::parse_commands(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(day9a::calculate_fields_covered_by_tail(parsed).into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(day9b::calculate_fields_covered_by_tail(parsed).into())
+    }
+}
@@ -0,0 +1,28 @@
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+
+use common::solution::DaySolver;
+
+/// All solved days, ordered by day.
+pub const SOLVERS: [DaySolver; 9] = [
+    DaySolver::of::<day01::Day1>(),
+    DaySolver::of::<day02::Day2>(),
+    DaySolver::of::<day03::Day3>(),
+    DaySolver::of::<day04::Day4>(),
+    DaySolver::of::<day05::Day5>(),
+    DaySolver::of::<day06::Day6>(),
+    DaySolver::of::<day07::Day7>(),
+    DaySolver::of::<day08::Day8>(),
+    DaySolver::of::<day09::Day9>(),
+];
+
+pub fn find_solver(day: u32) -> Option<&'static DaySolver> {
+    SOLVERS.iter().find(|s| s.day == day)
+}
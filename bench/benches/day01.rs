@@ -0,0 +1,21 @@
+use bench::{repeat_groups, SCALES};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+fn calories_per_elf(c: &mut Criterion) {
+    let puzzle_input = std::fs::read_to_string(day1::PUZZLE_INPUT_PATH).unwrap();
+    let mut group = c.benchmark_group("day01/sum_top_calories");
+
+    group.throughput(Throughput::Bytes(puzzle_input.len() as u64));
+    group.bench_function("puzzle_input", |b| b.iter(|| day1::sum_top_calories(&puzzle_input, 3).unwrap()));
+    for scale in SCALES {
+        let input = repeat_groups(&puzzle_input, scale);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("scaled", scale), &input, |b, input| {
+            b.iter(|| day1::sum_top_calories(input, 3).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, calories_per_elf);
+criterion_main!(benches);
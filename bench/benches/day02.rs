@@ -0,0 +1,22 @@
+use bench::{repeat_lines, SCALES};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day2::Strategy;
+
+fn total_score(c: &mut Criterion) {
+    let puzzle_input = std::fs::read_to_string(day2::PUZZLE_INPUT_PATH).unwrap();
+    let mut group = c.benchmark_group("day02/calculate_total_score");
+
+    group.throughput(Throughput::Bytes(puzzle_input.len() as u64));
+    group.bench_function("puzzle_input", |b| b.iter(|| day2::calculate_total_score(&puzzle_input, Strategy::Outcomes).unwrap()));
+    for scale in SCALES {
+        let input = repeat_lines(&puzzle_input, scale);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("scaled", scale), &input, |b, input| {
+            b.iter(|| day2::calculate_total_score(input, Strategy::Outcomes).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, total_score);
+criterion_main!(benches);
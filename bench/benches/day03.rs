@@ -0,0 +1,32 @@
+use bench::{repeat_lines, widen_rucksacks, SCALES};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use inputgen::Rng;
+
+fn priority_sums(c: &mut Criterion) {
+    let puzzle_input = std::fs::read_to_string(day3::PUZZLE_INPUT_PATH).unwrap();
+    let mut group = c.benchmark_group("day03/priority_sums");
+
+    let both_parts = |input: &str| {
+        (day3::calculate_compartment_sum(input).unwrap(), day3::calculate_badge_sum(input).unwrap())
+    };
+    group.throughput(Throughput::Bytes(puzzle_input.len() as u64));
+    group.bench_function("puzzle_input", |b| b.iter(|| both_parts(&puzzle_input)));
+    for scale in SCALES {
+        // more rucksacks and longer rucksacks
+        let input = repeat_lines(&puzzle_input, scale);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("scaled", scale), &input, |b, input| b.iter(|| both_parts(input)));
+        let input = widen_rucksacks(&puzzle_input, scale);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("widened", scale), &input, |b, input| b.iter(|| both_parts(input)));
+    }
+    // generated groups, then widened to very large rucksacks
+    let generated = inputgen::rucksacks(&mut Rng::new(3), 10_000);
+    let input = widen_rucksacks(&generated, 100);
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_with_input(BenchmarkId::new("generated", "10000x100"), &input, |b, input| b.iter(|| both_parts(input)));
+    group.finish();
+}
+
+criterion_group!(benches, priority_sums);
+criterion_main!(benches);
@@ -0,0 +1,21 @@
+use bench::{repeat_lines, SCALES};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+fn overlapping_pairs(c: &mut Criterion) {
+    let puzzle_input = std::fs::read_to_string(day4::PUZZLE_INPUT_PATH).unwrap();
+    let mut group = c.benchmark_group("day04/count_overlapping_pairs");
+
+    group.throughput(Throughput::Bytes(puzzle_input.len() as u64));
+    group.bench_function("puzzle_input", |b| b.iter(|| day4::count_overlapping_pairs(&puzzle_input).unwrap()));
+    for scale in SCALES {
+        let input = repeat_lines(&puzzle_input, scale);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("scaled", scale), &input, |b, input| {
+            b.iter(|| day4::count_overlapping_pairs(input).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, overlapping_pairs);
+criterion_main!(benches);
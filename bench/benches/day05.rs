@@ -0,0 +1,38 @@
+use bench::{repeat_moves, SCALES};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn apply_moves(c: &mut Criterion) {
+    let puzzle_input = std::fs::read_to_string(day5a::PUZZLE_INPUT_PATH).unwrap();
+    let mut inputs = vec![(String::from("puzzle_input"), puzzle_input.clone())];
+    inputs.extend(SCALES.iter().map(|scale| (format!("scaled/{}", scale), repeat_moves(&puzzle_input, *scale))));
+
+    let mut group = c.benchmark_group("day05/apply_moves");
+    for (name, input) in &inputs {
+        let stacks = day5a::Stacks::new(input).unwrap();
+        group.bench_with_input(BenchmarkId::new("crate_mover_9000", name), &stacks, |b, stacks| {
+            b.iter(|| stacks.clone().apply_moves().unwrap())
+        });
+        let stacks = day5b::Stacks::new(input).unwrap();
+        group.bench_with_input(BenchmarkId::new("crate_mover_9001", name), &stacks, |b, stacks| {
+            b.iter(|| stacks.clone().apply_moves().unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn parse_stacks(c: &mut Criterion) {
+    let puzzle_input = std::fs::read_to_string(day5a::PUZZLE_INPUT_PATH).unwrap();
+    let mut group = c.benchmark_group("day05/parse");
+
+    group.bench_function("puzzle_input", |b| b.iter(|| day5a::Stacks::new(&puzzle_input).unwrap()));
+    for scale in SCALES {
+        let input = repeat_moves(&puzzle_input, scale);
+        group.bench_with_input(BenchmarkId::new("scaled", scale), &input, |b, input| {
+            b.iter(|| day5a::Stacks::new(input).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, apply_moves, parse_stacks);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+use bench::{stream_with_late_marker, SCALES};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+fn start_signal_position(c: &mut Criterion) {
+    let puzzle_input = std::fs::read_to_string(day6a::PUZZLE_INPUT_PATH).unwrap();
+    let mut group = c.benchmark_group("day06/find_start_signal_position");
+
+    group.throughput(Throughput::Bytes(puzzle_input.len() as u64));
+    group.bench_function("packet/puzzle_input", |b| b.iter(|| day6a::find_start_signal_position(&puzzle_input)));
+    group.bench_function("message/puzzle_input", |b| b.iter(|| day6b::find_start_signal_position(&puzzle_input)));
+    // the marker of the puzzle input shows up early, the synthetic streams have it at their very end
+    for scale in SCALES {
+        let len = puzzle_input.len() * scale;
+        group.throughput(Throughput::Bytes(len as u64));
+        let packet_stream = stream_with_late_marker(len, 4);
+        group.bench_with_input(BenchmarkId::new("packet/scaled", scale), &packet_stream, |b, input| {
+            b.iter(|| day6a::find_start_signal_position(input))
+        });
+        let message_stream = stream_with_late_marker(len, 14);
+        group.bench_with_input(BenchmarkId::new("message/scaled", scale), &message_stream, |b, input| {
+            b.iter(|| day6b::find_start_signal_position(input))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, start_signal_position);
+criterion_main!(benches);
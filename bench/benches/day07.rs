@@ -0,0 +1,27 @@
+use bench::{repeat_transcript, SCALES};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+fn smallest_dir_to_delete(input: &str) -> u64 {
+    let root = day7b::get_directories(input).unwrap();
+    let required_space = day7b::get_required_space(&root);
+    day7b::size_of_smallest_dir_above(required_space, &root)
+}
+
+fn directories(c: &mut Criterion) {
+    let puzzle_input = std::fs::read_to_string(day7b::PUZZLE_INPUT_PATH).unwrap();
+    let mut group = c.benchmark_group("day07/smallest_dir_to_delete");
+
+    group.throughput(Throughput::Bytes(puzzle_input.len() as u64));
+    group.bench_function("puzzle_input", |b| b.iter(|| smallest_dir_to_delete(&puzzle_input)));
+    for scale in SCALES {
+        let input = repeat_transcript(&puzzle_input, scale);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("scaled", scale), &input, |b, input| {
+            b.iter(|| smallest_dir_to_delete(input))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, directories);
+criterion_main!(benches);
@@ -0,0 +1,25 @@
+use bench::{tile_grid, SCALES};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day8ab::Forest;
+
+fn forest(c: &mut Criterion) {
+    let puzzle_input = std::fs::read_to_string(day8ab::PUZZLE_INPUT_PATH).unwrap();
+    let mut forests = vec![(String::from("puzzle_input"), Forest::new(&puzzle_input).unwrap())];
+    forests.extend(SCALES.iter().map(|scale| (format!("scaled/{}", scale), Forest::new(&tile_grid(&puzzle_input, *scale)).unwrap())));
+
+    let mut group = c.benchmark_group("day08");
+    // every tree looks along its row and column, so the larger forests take a while
+    group.sample_size(10);
+    for (name, forest) in &forests {
+        group.bench_with_input(BenchmarkId::new("get_visible_trees", name), forest, |b, forest| {
+            b.iter(|| forest.get_visible_trees())
+        });
+        group.bench_with_input(BenchmarkId::new("calculate_scenic_score_max", name), forest, |b, forest| {
+            b.iter(|| forest.calculate_scenic_score_max())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, forest);
+criterion_main!(benches);
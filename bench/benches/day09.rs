@@ -0,0 +1,25 @@
+use bench::{repeat_lines, SCALES};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn fields_covered_by_tail(c: &mut Criterion) {
+    let puzzle_input = std::fs::read_to_string(day9b::PUZZLE_INPUT_PATH).unwrap();
+    let mut inputs = vec![(String::from("puzzle_input"), day9b::parse_commands(&puzzle_input).unwrap())];
+    inputs.extend(SCALES.iter().map(|scale| {
+        (format!("scaled/{}", scale), day9b::parse_commands(&repeat_lines(&puzzle_input, *scale)).unwrap())
+    }));
+
+    let mut group = c.benchmark_group("day09/calculate_fields_covered_by_tail");
+    group.sample_size(10);
+    for (name, commands) in &inputs {
+        group.bench_with_input(BenchmarkId::new("two_knots", name), commands, |b, commands| {
+            b.iter(|| day9a::calculate_fields_covered_by_tail(commands))
+        });
+        group.bench_with_input(BenchmarkId::new("ten_knots", name), commands, |b, commands| {
+            b.iter(|| day9b::calculate_fields_covered_by_tail(commands))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, fields_covered_by_tail);
+criterion_main!(benches);
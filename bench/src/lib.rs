@@ -0,0 +1,135 @@
+//! Helpers that blow up the bundled puzzle inputs for the benchmarks in `benches/`.
+//!
+//! Every helper keeps the input valid for its day, so the scaled inputs exercise
+//! the same code paths as the original ones.
+
+/// Factors by which the puzzle inputs are scaled.
+pub const SCALES: [usize; 3] = [10, 100, 1000];
+
+/// Repeats a line based input `factor` times.
+pub fn repeat_lines(input: &str, factor: usize) -> String {
+    vec![input.trim_end(); factor].join("\n")
+}
+
+/// Repeats an input made of groups separated by empty lines `factor` times.
+pub fn repeat_groups(input: &str, factor: usize) -> String {
+    vec![input.trim_end(); factor].join("\n\n")
+}
+
+/// Repeats the items of both compartments of every rucksack `factor` times.
+/// The item types of each compartment stay the same, so do the shared items and badges.
+pub fn widen_rucksacks(input: &str, factor: usize) -> String {
+    let lines: Vec<String> = input.lines()
+        .map(|line| {
+            let (first, second) = line.split_at(line.len() / 2);
+            first.repeat(factor) + &second.repeat(factor)
+        })
+        .collect();
+    lines.join("\n")
+}
+
+fn invert_move(line: &str) -> String {
+    let tokens: Vec<&str> = line.split(' ').collect();
+    format!("move {} from {} to {}", tokens[1], tokens[5], tokens[3])
+}
+
+/// Repeats the moves of a crate input `factor` times.
+///
+/// Every second repetition runs the moves backwards, which restores the initial
+/// stacks, so no repetition moves a crate from an empty stack.
+pub fn repeat_moves(input: &str, factor: usize) -> String {
+    let (crates, moves) = input.split_once("\n\n").unwrap();
+    let forward: Vec<&str> = moves.lines().collect();
+    let backward: Vec<String> = forward.iter().rev().map(|l| invert_move(l)).collect();
+
+    let mut result = String::from(crates);
+    result.push('\n');
+    for i in 0..factor {
+        if i % 2 == 0 {
+            forward.iter().for_each(|l| { result.push('\n'); result.push_str(l); });
+        } else {
+            backward.iter().for_each(|l| { result.push('\n'); result.push_str(l); });
+        }
+    }
+    result
+}
+
+/// Repeats a terminal transcript `factor` times, each copy inside its own directory below `/`.
+pub fn repeat_transcript(input: &str, factor: usize) -> String {
+    let body = input.trim_end().strip_prefix("$ cd /\n").unwrap_or(input);
+    let mut result = String::new();
+    for i in 0..factor {
+        result.push_str(&format!("$ cd /\n$ cd copy{}\n", i));
+        result.push_str(body);
+        result.push('\n');
+    }
+    result
+}
+
+/// Tiles a character map so that it contains roughly `factor` times as many cells.
+pub fn tile_grid(input: &str, factor: usize) -> String {
+    let repetitions = ((factor as f64).sqrt().round() as usize).max(1);
+    let rows: Vec<String> = input.lines().map(|l| l.repeat(repetitions)).collect();
+    let tile = rows.join("\n");
+    vec![tile; repetitions].join("\n")
+}
+
+/// A stream of `len` characters whose first marker of `marker_len` distinct characters is at the very end.
+pub fn stream_with_late_marker(len: usize, marker_len: usize) -> String {
+    let alphabet: Vec<char> = ('a'..='z').collect();
+    let period = marker_len - 1;
+    let mut stream: String = (0..len.saturating_sub(marker_len)).map(|i| alphabet[i % period]).collect();
+    stream.extend(alphabet[alphabet.len() - marker_len..].iter());
+    stream
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn repeat_lines_multiplies_line_count() {
+        assert_eq!(repeat_lines("A Y\nB X\n", 3), "A Y\nB X\nA Y\nB X\nA Y\nB X");
+    }
+
+    #[test]
+    fn repeat_groups_keeps_groups_separated() {
+        assert_eq!(repeat_groups("1\n2\n\n3\n", 2), "1\n2\n\n3\n\n1\n2\n\n3");
+    }
+
+    #[test]
+    fn widen_rucksacks_keeps_compartments_apart() {
+        assert_eq!(widen_rucksacks("abcb\nxyzy", 2), "ababcbcb\nxyxyzyzy");
+    }
+
+    #[test]
+    fn repeat_moves_alternates_forward_and_backward() {
+        let input = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nmove 2 from 2 to 1";
+        let expected = "[A] [B]\n 1   2 \n\n\
+            move 1 from 1 to 2\nmove 2 from 2 to 1\n\
+            move 2 from 1 to 2\nmove 1 from 2 to 1\n\
+            move 1 from 1 to 2\nmove 2 from 2 to 1";
+        assert_eq!(repeat_moves(input, 3), expected);
+    }
+
+    #[test]
+    fn repeat_transcript_places_copies_in_sub_directories() {
+        let input = "$ cd /\n$ ls\n10 a.txt\n";
+        let expected = "$ cd /\n$ cd copy0\n$ ls\n10 a.txt\n$ cd /\n$ cd copy1\n$ ls\n10 a.txt\n";
+        assert_eq!(repeat_transcript(input, 2), expected);
+    }
+
+    #[test]
+    fn tile_grid_repeats_in_both_directions() {
+        assert_eq!(tile_grid("12\n34", 4), "1212\n3434\n1212\n3434");
+        assert_eq!(tile_grid("12\n34", 1), "12\n34");
+    }
+
+    #[test]
+    fn stream_with_late_marker_has_marker_only_at_the_end() {
+        let stream = stream_with_late_marker(100, 4);
+        assert_eq!(stream.len(), 100);
+        assert!(stream.ends_with("wxyz"));
+        assert!(stream.starts_with("abcabc"));
+    }
+}
@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::fmt;
+
+/// Number of characters shown on each side of the offending column.
+const SNIPPET_RADIUS: usize = 30;
+
+/// Position of a problem in the puzzle input together with the offending line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The complete offending line.
+    pub snippet: String,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize, snippet: &str) -> Self {
+        Self { line, column, snippet: snippet.to_string() }
+    }
+
+    /// Location of `part` inside `text`, the content of line `line`.
+    /// `part` has to be a sub slice of `text`, otherwise the column is meaningless.
+    pub fn of(line: usize, text: &str, part: &str) -> Self {
+        let start = text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        let column = if offset <= text.len() && text.is_char_boundary(offset) {
+            text[..offset].chars().count() + 1
+        } else {
+            1
+        };
+        Self::new(line, column, text)
+    }
+
+    /// Moves the location to another line, for parsers of single lines that do not know their line number.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chars: Vec<char> = self.snippet.chars().collect();
+        let column_idx = self.column.saturating_sub(1).min(chars.len());
+        let first = column_idx.saturating_sub(SNIPPET_RADIUS);
+        let last = (column_idx + SNIPPET_RADIUS).min(chars.len());
+
+        let prefix = if first > 0 { "..." } else { "" };
+        let suffix = if last < chars.len() { "..." } else { "" };
+        let excerpt: String = chars[first..last].iter().collect();
+        let caret_offset = prefix.len() + column_idx - first;
+
+        writeln!(f, "line {}, column {}:", self.line, self.column)?;
+        writeln!(f, "    {}{}{}", prefix, excerpt, suffix)?;
+        write!(f, "    {}^", " ".repeat(caret_offset))
+    }
+}
+
+/// Error type for `main` functions.
+///
+/// Returning an error from `main` prints its `Debug` representation,
+/// `Report` forwards that to `Display` so that users see the readable diagnostic.
+pub struct Report(Box<dyn Error>);
+
+impl<E: Error + 'static> From<E> for Report {
+    fn from(error: E) -> Self {
+        Report(Box::new(error))
+    }
+}
+
+impl fmt::Debug for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn location_of_sub_slice() {
+        let line = "move 1 from x to 3";
+        let part = &line[12..13];
+        let location = Location::of(4, line, part);
+        assert_eq!(location.line, 4);
+        assert_eq!(location.column, 13);
+        assert_eq!(location.snippet, line);
+    }
+
+    #[test]
+    fn location_of_empty_slice_at_line_end() {
+        let line = "move 1";
+        let location = Location::of(1, line, &line[line.len()..]);
+        assert_eq!(location.column, 7);
+    }
+
+    #[test]
+    fn location_display_marks_column() {
+        let location = Location::new(2, 3, "2-x,4-5");
+        assert_eq!(location.to_string(), "line 2, column 3:\n    2-x,4-5\n      ^");
+    }
+
+    #[test]
+    fn location_display_shortens_long_lines() {
+        let line = "a".repeat(100) + "!" + &"b".repeat(100);
+        let location = Location::new(1, 101, &line);
+        let expected = format!("line 1, column 101:\n    ...{}!{}...\n    {}^", "a".repeat(30), "b".repeat(29), " ".repeat(33));
+        assert_eq!(location.to_string(), expected);
+    }
+}
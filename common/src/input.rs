@@ -0,0 +1,81 @@
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+
+/// Command line value that selects stdin instead of a file.
+pub const STDIN_ARG: &str = "-";
+
+/// Reads puzzle input from `path`, from stdin if `path` is `-`,
+/// or returns the `bundled` puzzle input if no path is given.
+pub fn read_input(path: Option<&str>, bundled: &str) -> io::Result<String> {
+    match path {
+        Some(STDIN_ARG) => {
+            let mut contents = String::new();
+            io::stdin().read_to_string(&mut contents)?;
+            Ok(contents)
+        }
+        Some(path) => fs::read_to_string(path),
+        None => Ok(bundled.to_string()),
+    }
+}
+
+/// Opens the same input as [`read_input`] for reading line by line, without loading files as a whole.
+pub fn open_input(path: Option<&str>, bundled: &'static str) -> io::Result<Box<dyn BufRead>> {
+    match path {
+        Some(STDIN_ARG) => Ok(Box::new(io::stdin().lock())),
+        Some(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+        None => Ok(Box::new(bundled.as_bytes())),
+    }
+}
+
+/// Reads the puzzle input selected by the first command line argument of a day binary.
+pub fn read_input_from_args(bundled: &str) -> io::Result<String> {
+    let path = std::env::args().nth(1);
+    read_input(path.as_deref(), bundled)
+}
+
+/// 64 bit FNV-1a hash of the input as 16 hex digits, to tell apart runs on different inputs.
+pub fn checksum(contents: &str) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+    let hash = contents.bytes().fold(OFFSET_BASIS, |hash, byte| (hash ^ byte as u64).wrapping_mul(PRIME));
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_input_without_path_returns_bundled_input() {
+        assert_eq!(read_input(None, "R 4\nU 4").unwrap(), "R 4\nU 4");
+    }
+
+    #[test]
+    fn read_input_prefers_given_path_over_bundled_input() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/lib.rs");
+        let contents = read_input(Some(path), "bundled").unwrap();
+        assert!(contents.contains("pub mod input;"));
+    }
+
+    #[test]
+    fn read_input_from_missing_file_is_an_error() {
+        assert!(read_input(Some("does_not_exist.txt"), "bundled").is_err());
+    }
+
+    #[test]
+    fn open_input_reads_lines_of_given_path_or_bundled_input() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+        let first_line = open_input(Some(path), "bundled").unwrap().lines().next().unwrap().unwrap();
+        assert_eq!(first_line, "[package]");
+        let lines: Vec<String> = open_input(None, "1000\n2000").unwrap().lines().map(Result::unwrap).collect();
+        assert_eq!(lines, vec!["1000", "2000"]);
+        assert!(open_input(Some("does_not_exist.txt"), "bundled").is_err());
+    }
+
+    #[test]
+    fn checksum_is_fnv1a() {
+        assert_eq!(checksum(""), "cbf29ce484222325");
+        assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+        assert_ne!(checksum("R 4\nU 4"), checksum("R 4\nU 5"));
+    }
+}
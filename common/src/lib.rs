@@ -0,0 +1,4 @@
+pub mod diagnostic;
+pub mod input;
+pub mod rng;
+pub mod solution;
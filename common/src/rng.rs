@@ -0,0 +1,66 @@
+/// SplitMix64 pseudo random number generator.
+///
+/// Small and fully specified, so a seed produces the same numbers on every machine and
+/// with every dependency version.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform number in `low..=high`.
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next_u64() % (high - low + 1)
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0, len as u64 - 1) as usize
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        assert!((0..100).all(|_| a.next_u64() == b.next_u64()));
+    }
+
+    #[test]
+    fn splitmix64_reference_value() {
+        assert_eq!(Rng::new(0).next_u64(), 0xe220a8397b1dcdaf);
+    }
+
+    #[test]
+    fn range_is_inclusive() {
+        let mut rng = Rng::new(1);
+        let values: Vec<u64> = (0..1000).map(|_| rng.range(3, 5)).collect();
+        assert!(values.iter().all(|v| (3..=5).contains(v)));
+        assert!(values.contains(&3) && values.contains(&5));
+    }
+}
@@ -0,0 +1,249 @@
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Answer of a single puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    NotImplemented,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Number(n)
+    }
+}
+
+/// Numbers beyond `i64::MAX` are kept as text.
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Number)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+/// A solver for both parts of one day.
+///
+/// The input is parsed once and both parts work on the parsed representation.
+/// Parts that are not solved yet keep the default implementation.
+pub trait Solution {
+    const DAY: u32;
+    /// The bundled puzzle input, used when no input is given.
+    const PUZZLE_INPUT: &'static str;
+
+    type Parsed;
+    type Error: Error + 'static;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error>;
+
+    fn part1(_parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(Answer::NotImplemented)
+    }
+
+    fn part2(_parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+        Ok(Answer::NotImplemented)
+    }
+}
+
+pub const PARTS: [u32; 2] = [1, 2];
+
+pub type SolveResult = Result<Answer, Box<dyn Error>>;
+
+/// Answer of a part together with the time spent parsing the input and solving the part.
+/// Parts solved together share one parse and report the same parse time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedAnswer {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+pub type TimedSolveResult = Result<TimedAnswer, Box<dyn Error>>;
+
+/// Parses the input once and solves each of `parts` on the parsed value.
+/// If parsing fails, every part fails with the parse error.
+fn solve_parts<S: Solution>(parts: &[u32], input: &str) -> Vec<TimedSolveResult> {
+    let parse_start = Instant::now();
+    let parsed = match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            let message = error.to_string();
+            return parts.iter().map(|_| Err(message.clone().into())).collect();
+        }
+    };
+    let parse_time = parse_start.elapsed();
+
+    parts.iter()
+        .map(|part| {
+            let solve_start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&parsed)?,
+                2 => S::part2(&parsed)?,
+                _ => Answer::NotImplemented,
+            };
+            Ok(TimedAnswer { answer, parse_time, solve_time: solve_start.elapsed() })
+        })
+        .collect()
+}
+
+/// Type erased [`Solution`] so that days can be listed in a registry.
+#[derive(Clone, Copy)]
+pub struct DaySolver {
+    pub day: u32,
+    pub puzzle_input: &'static str,
+    solve: fn(&[u32], &str) -> Vec<TimedSolveResult>,
+}
+
+impl DaySolver {
+    pub const fn of<S: Solution>() -> Self {
+        Self { day: S::DAY, puzzle_input: S::PUZZLE_INPUT, solve: solve_parts::<S> }
+    }
+
+    pub fn solve(&self, part: u32, input: &str) -> SolveResult {
+        self.solve_timed(part, input).map(|timed| timed.answer)
+    }
+
+    pub fn solve_timed(&self, part: u32, input: &str) -> TimedSolveResult {
+        self.solve_parts_timed(&[part], input).pop().unwrap()
+    }
+
+    /// Solves several parts of the same input, parsing it only once.
+    pub fn solve_parts_timed(&self, parts: &[u32], input: &str) -> Vec<TimedSolveResult> {
+        (self.solve)(parts, input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const DAY: u32 = 42;
+        const PUZZLE_INPUT: &'static str = "";
+
+        type Parsed = String;
+        type Error = std::num::ParseIntError;
+
+        fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+            input.parse::<i32>()?;
+            Ok(input.to_string())
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+            Ok(parsed.len().into())
+        }
+    }
+
+    #[test]
+    fn day_solver_runs_implemented_part() {
+        let solver = DaySolver::of::<Echo>();
+        assert_eq!(solver.day, 42);
+        assert_eq!(solver.solve(1, "123").unwrap(), Answer::Number(3));
+    }
+
+    #[test]
+    fn day_solver_reports_missing_part_as_not_implemented() {
+        let solver = DaySolver::of::<Echo>();
+        assert_eq!(solver.solve(2, "123").unwrap(), Answer::NotImplemented);
+        assert_eq!(solver.solve(3, "123").unwrap(), Answer::NotImplemented);
+    }
+
+    #[test]
+    fn day_solver_forwards_parse_errors() {
+        let solver = DaySolver::of::<Echo>();
+        assert!(solver.solve(1, "abc").is_err());
+    }
+
+    #[test]
+    fn day_solver_measures_parse_and_solve_time() {
+        let solver = DaySolver::of::<Echo>();
+        let timed = solver.solve_timed(1, "123").unwrap();
+        assert_eq!(timed.answer, Answer::Number(3));
+        assert!(timed.parse_time + timed.solve_time < Duration::from_secs(1));
+    }
+
+    static PARSE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct CountingParses;
+
+    impl Solution for CountingParses {
+        const DAY: u32 = 43;
+        const PUZZLE_INPUT: &'static str = "";
+
+        type Parsed = usize;
+        type Error = std::num::ParseIntError;
+
+        fn parse(input: &str) -> Result<Self::Parsed, Self::Error> {
+            PARSE_COUNT.fetch_add(1, Ordering::SeqCst);
+            input.parse()
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+            Ok((parsed + 1).into())
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Answer, Self::Error> {
+            Ok((parsed + 2).into())
+        }
+    }
+
+    #[test]
+    fn day_solver_parses_once_for_both_parts() {
+        let solver = DaySolver::of::<CountingParses>();
+        let results = solver.solve_parts_timed(&PARTS, "40");
+        assert_eq!(PARSE_COUNT.load(Ordering::SeqCst), 1);
+        let results: Vec<TimedAnswer> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!((&results[0].answer, &results[1].answer), (&Answer::Number(41), &Answer::Number(42)));
+        assert_eq!(results[0].parse_time, results[1].parse_time);
+        let errors = solver.solve_parts_timed(&PARTS, "x");
+        assert!(errors.iter().all(|result| result.as_ref().unwrap_err().to_string() == "invalid digit found in string"));
+    }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(95437).to_string(), "95437");
+        assert_eq!(Answer::from(String::from("CMZ")).to_string(), "CMZ");
+        assert_eq!(Answer::NotImplemented.to_string(), "not implemented");
+        assert_eq!(Answer::from(u64::MAX), Answer::Text(u64::MAX.to_string()));
+        assert_eq!(Answer::from(45000u64), Answer::Number(45000));
+    }
+}
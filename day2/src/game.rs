@@ -0,0 +1,376 @@
+//! Hand games with any number of shapes, such as Rock-Paper-Scissors-Lizard-Spock.
+//!
+//! A game is a tournament: of two different shapes exactly one defeats the other.
+//! Games are built in or read from a config file like
+//!
+//! ```text
+//! # scores of a loss, a draw and a win
+//! outcomes 0 3 6
+//! # name, opponent symbol, response symbol, score
+//! shape Rock     A X 1
+//! shape Paper    B Y 2
+//! shape Scissors C Z 3
+//! # optional, by default shape i defeats shape j if i - j is odd modulo the number of shapes
+//! beats Rock Scissors
+//! ```
+
+use std::fmt;
+
+use common::diagnostic::Location;
+
+use crate::{Outcome, ParseError, Strategy};
+
+pub const ROCK_PAPER_SCISSORS: &str = "\
+outcomes 0 3 6
+shape Rock     A X 1
+shape Paper    B Y 2
+shape Scissors C Z 3
+";
+
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str = "\
+outcomes 0 3 6
+shape Rock     A V 1
+shape Paper    B W 2
+shape Scissors C X 3
+shape Spock    D Y 4
+shape Lizard   E Z 5
+";
+
+pub const BUILTIN_GAMES: [(&str, &str); 2] = [("rps", ROCK_PAPER_SCISSORS), ("rpsls", ROCK_PAPER_SCISSORS_LIZARD_SPOCK)];
+
+/// Symbols are single letters, so a generated cyclic game has at most 13 shapes.
+const MAX_CYCLIC_SHAPES: usize = 13;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GameError {
+    InvalidLine(Location),
+    DuplicateShape(Location),
+    UnknownShape(Location),
+    /// Two shapes that do not defeat each other exactly one way.
+    NotATournament(String, String),
+    /// Too few shapes, or an even number of shapes without explicit `beats` lines.
+    ShapeCount(usize),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::InvalidLine(location) => write!(f, "expected 'outcomes', 'shape' or 'beats' line at {}", location),
+            GameError::DuplicateShape(location) => write!(f, "shape name or symbol is already used at {}", location),
+            GameError::UnknownShape(location) => write!(f, "unknown shape at {}", location),
+            GameError::NotATournament(a, b) => write!(f, "exactly one of {} and {} must defeat the other", a, b),
+            GameError::ShapeCount(count) => write!(f, "{} shapes need explicit 'beats' lines, a cyclic game needs an odd number of at least 3", count),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+/// A line of a strategy guide that can not be played in a game.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RoundError {
+    Guide(ParseError),
+    /// No shape of the game reaches the outcome against the opponent's shape.
+    ImpossibleOutcome(Location),
+}
+
+impl fmt::Display for RoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundError::Guide(e) => write!(f, "{}", e),
+            RoundError::ImpossibleOutcome(location) => write!(f, "no shape reaches this outcome at {}", location),
+        }
+    }
+}
+
+impl std::error::Error for RoundError {}
+
+impl From<ParseError> for RoundError {
+    fn from(e: ParseError) -> Self {
+        RoundError::Guide(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShapeDef {
+    pub name: String,
+    pub opponent_symbol: String,
+    pub response_symbol: String,
+    pub score: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub shapes: Vec<ShapeDef>,
+    /// `defeats[i][j]` if shape `i` defeats shape `j`.
+    defeats: Vec<Vec<bool>>,
+    /// Scores of a loss, a draw and a win.
+    pub outcome_scores: [i32; 3],
+}
+
+/// Outcome scores of the puzzle, the ones of [`crate::scoring`].
+fn default_outcome_scores() -> [i32; 3] {
+    Outcome::ALL.map(Outcome::score)
+}
+
+/// Shape `i` defeats shape `j` if `i - j` is odd modulo `n`, a tournament for odd `n`.
+fn cyclic_defeats(n: usize) -> Vec<Vec<bool>> {
+    (0..n).map(|i| (0..n).map(|j| (i + n - j) % n % 2 == 1).collect()).collect()
+}
+
+fn number(line_number: usize, line: &str, field: &str) -> Result<i32, GameError> {
+    field.parse().map_err(|_| GameError::InvalidLine(Location::of(line_number, line, field)))
+}
+
+impl Game {
+    pub fn builtin(name: &str) -> Option<Game> {
+        BUILTIN_GAMES.iter()
+            .find(|(builtin, _)| *builtin == name)
+            .map(|(_, config)| Game::from_config(config).expect("built in games are valid"))
+    }
+
+    /// A cyclic game of `n` shapes named `S1` to `Sn`, with opponent symbols from `A` on,
+    /// response symbols up to `Z` and shape scores from 1 to `n`.
+    pub fn cyclic(n: usize) -> Result<Game, GameError> {
+        if n < 3 || n.is_multiple_of(2) || n > MAX_CYCLIC_SHAPES {
+            return Err(GameError::ShapeCount(n));
+        }
+        let letter = |offset: usize| char::from(b'A' + offset as u8).to_string();
+        let shapes = (0..n)
+            .map(|i| ShapeDef {
+                name: format!("S{}", i + 1),
+                opponent_symbol: letter(i),
+                response_symbol: letter(26 - n + i),
+                score: i as i32 + 1,
+            })
+            .collect();
+        Ok(Game { shapes, defeats: cyclic_defeats(n), outcome_scores: default_outcome_scores() })
+    }
+
+    pub fn from_config(config: &str) -> Result<Game, GameError> {
+        let mut shapes: Vec<ShapeDef> = vec![];
+        let mut outcome_scores = default_outcome_scores();
+        let mut beats_lines = vec![];
+        for (i, line) in config.lines().enumerate() {
+            let line_number = i + 1;
+            let content = line.split('#').next().unwrap();
+            let fields: Vec<&str> = content.split_whitespace().collect();
+            match fields.as_slice() {
+                [] => {}
+                ["outcomes", lose, draw, win] => {
+                    outcome_scores = [number(line_number, line, lose)?, number(line_number, line, draw)?, number(line_number, line, win)?];
+                }
+                ["shape", name, opponent_symbol, response_symbol, score] => {
+                    let duplicate = |field: &str| GameError::DuplicateShape(Location::of(line_number, line, field));
+                    if shapes.iter().any(|s| s.name == *name) {
+                        return Err(duplicate(name));
+                    }
+                    if shapes.iter().any(|s| s.opponent_symbol == *opponent_symbol) {
+                        return Err(duplicate(opponent_symbol));
+                    }
+                    if shapes.iter().any(|s| s.response_symbol == *response_symbol) {
+                        return Err(duplicate(response_symbol));
+                    }
+                    shapes.push(ShapeDef {
+                        name: name.to_string(),
+                        opponent_symbol: opponent_symbol.to_string(),
+                        response_symbol: response_symbol.to_string(),
+                        score: number(line_number, line, score)?,
+                    });
+                }
+                ["beats", _, losers @ ..] if !losers.is_empty() => beats_lines.push((line_number, line, fields)),
+                [first, ..] => return Err(GameError::InvalidLine(Location::of(line_number, line, first))),
+            }
+        }
+
+        let n = shapes.len();
+        let defeats = if beats_lines.is_empty() {
+            if n < 3 || n.is_multiple_of(2) {
+                return Err(GameError::ShapeCount(n));
+            }
+            cyclic_defeats(n)
+        } else {
+            if n < 2 {
+                return Err(GameError::ShapeCount(n));
+            }
+            let mut defeats = vec![vec![false; n]; n];
+            for (line_number, line, fields) in beats_lines {
+                let index = |field: &str| shapes.iter().position(|s| s.name == field)
+                    .ok_or_else(|| GameError::UnknownShape(Location::of(line_number, line, field)));
+                let winner = index(fields[1])?;
+                for loser in &fields[2..] {
+                    defeats[winner][index(loser)?] = true;
+                }
+            }
+            defeats
+        };
+        for i in 0..n {
+            for j in i..n {
+                if (i == j && defeats[i][j]) || (i != j && defeats[i][j] == defeats[j][i]) {
+                    return Err(GameError::NotATournament(shapes[i].name.clone(), shapes[j].name.clone()));
+                }
+            }
+        }
+        Ok(Game { shapes, defeats, outcome_scores })
+    }
+
+    pub fn defeats(&self, shape: usize, other: usize) -> bool {
+        self.defeats[shape][other]
+    }
+
+    pub fn outcome(&self, response: usize, opponent: usize) -> Outcome {
+        if self.defeats[response][opponent] {
+            Outcome::Win
+        } else if response == opponent {
+            Outcome::Draw
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> i32 {
+        match outcome {
+            Outcome::Lose => self.outcome_scores[0],
+            Outcome::Draw => self.outcome_scores[1],
+            Outcome::Win => self.outcome_scores[2],
+        }
+    }
+
+    pub fn score(&self, response: usize, opponent: usize) -> i32 {
+        self.shapes[response].score + self.outcome_score(self.outcome(response, opponent))
+    }
+
+    /// The highest scoring shape that reaches `outcome` against `opponent`,
+    /// `None` if no shape does, e.g. a win against a shape that defeats all others.
+    pub fn response_to(&self, outcome: Outcome, opponent: usize) -> Option<usize> {
+        (0..self.shapes.len())
+            .filter(|response| self.outcome(*response, opponent) == outcome)
+            .max_by_key(|response| (self.shapes[*response].score, std::cmp::Reverse(*response)))
+    }
+
+    /// Parses line `line_number` of a strategy guide into the indices of the opponent's shape and ours.
+    pub fn parse_round(&self, line_number: usize, line: &str, strategy: Strategy) -> Result<(usize, usize), RoundError> {
+        let (opponent_symbol, response_symbol) = line.split_once(' ')
+            .ok_or(ParseError::MissingColumn(Location::new(line_number, line.chars().count() + 1, line)))?;
+        let opponent = self.shapes.iter().position(|s| s.opponent_symbol == opponent_symbol)
+            .ok_or_else(|| ParseError::UnknownOpponentShape(Location::of(line_number, line, opponent_symbol)))?;
+        let unknown_response = || ParseError::UnknownResponse(Location::of(line_number, line, response_symbol));
+        let response = match strategy {
+            Strategy::Shapes => self.shapes.iter().position(|s| s.response_symbol == response_symbol)
+                .ok_or_else(unknown_response)?,
+            Strategy::Outcomes => {
+                let outcome: Outcome = response_symbol.parse().map_err(|_| unknown_response())?;
+                self.response_to(outcome, opponent)
+                    .ok_or_else(|| RoundError::ImpossibleOutcome(Location::of(line_number, line, response_symbol)))?
+            }
+        };
+        Ok((opponent, response))
+    }
+
+    pub fn total_score(&self, contents: &str, strategy: Strategy) -> Result<i32, RoundError> {
+        contents.lines().enumerate()
+            .map(|(i, line)| {
+                let (opponent, response) = self.parse_round(i + 1, line, strategy)?;
+                Ok(self.score(response, opponent))
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::calculate_total_score;
+
+    fn index(game: &Game, name: &str) -> usize {
+        game.shapes.iter().position(|s| s.name == name).unwrap()
+    }
+
+    #[test]
+    fn rock_paper_scissors_matches_day2_scoring() {
+        let game = Game::builtin("rps").unwrap();
+        for contents in [include_str!("../example.txt"), include_str!("../puzzle_input.txt")] {
+            for strategy in Strategy::ALL {
+                assert_eq!(game.total_score(contents, strategy).unwrap(), calculate_total_score(contents, strategy).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock_relations() {
+        let game = Game::builtin("rpsls").unwrap();
+        let beaten = |name: &str| -> Vec<&str> {
+            (0..5).filter(|j| game.defeats(index(&game, name), *j)).map(|j| game.shapes[j].name.as_str()).collect()
+        };
+        assert_eq!(beaten("Rock"), vec!["Scissors", "Lizard"]);
+        assert_eq!(beaten("Paper"), vec!["Rock", "Spock"]);
+        assert_eq!(beaten("Scissors"), vec!["Paper", "Lizard"]);
+        assert_eq!(beaten("Spock"), vec!["Rock", "Scissors"]);
+        assert_eq!(beaten("Lizard"), vec!["Paper", "Spock"]);
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock_scoring() {
+        let game = Game::builtin("rpsls").unwrap();
+        // lizard loses to rock
+        assert_eq!(game.total_score("A Z", Strategy::Shapes), Ok(5));
+        // paper and spock both beat rock, spock scores more
+        assert_eq!(game.total_score("A Z", Strategy::Outcomes), Ok(10));
+        // paper and spock both lose to lizard
+        assert_eq!(game.total_score("E X", Strategy::Outcomes), Ok(4));
+        let error = game.total_score("F V", Strategy::Shapes).unwrap_err();
+        assert_eq!(error, RoundError::Guide(ParseError::UnknownOpponentShape(Location::new(1, 1, "F V"))));
+    }
+
+    #[test]
+    fn cyclic_games_are_balanced() {
+        let game = Game::cyclic(7).unwrap();
+        for i in 0..7 {
+            assert_eq!((0..7).filter(|j| game.defeats(i, *j)).count(), 3);
+        }
+        assert_eq!(game.shapes[0].opponent_symbol, "A");
+        assert_eq!(game.shapes[0].response_symbol, "T");
+        assert_eq!(game.shapes[6].response_symbol, "Z");
+        assert_eq!(Game::cyclic(4), Err(GameError::ShapeCount(4)));
+        assert_eq!(Game::cyclic(15), Err(GameError::ShapeCount(15)));
+    }
+
+    #[test]
+    fn config_with_explicit_beats() {
+        let config = "outcomes 0 1 2 # cheap\nshape Fire A X 1\nshape Water B Y 1\nshape Grass C Z 1\n\
+            beats Water Fire\nbeats Fire Grass\nbeats Grass Water\n";
+        let game = Game::from_config(config).unwrap();
+        assert!(game.defeats(1, 0));
+        assert_eq!(game.total_score("A Y\nC Y", Strategy::Shapes), Ok(3 + 1));
+    }
+
+    #[test]
+    fn impossible_outcome_is_an_error() {
+        let config = "shape Top A X 1\nshape Middle B Y 2\nshape Bottom C Z 3\nbeats Top Middle Bottom\nbeats Middle Bottom";
+        let game = Game::from_config(config).unwrap();
+        let error = game.total_score("B Z\nA Z", Strategy::Outcomes).unwrap_err();
+        assert_eq!(error, RoundError::ImpossibleOutcome(Location::new(2, 3, "A Z")));
+        assert_eq!(error.to_string(), "no shape reaches this outcome at line 2, column 3:\n    A Z\n      ^");
+    }
+
+    #[test]
+    fn invalid_configs() {
+        let error = Game::from_config("shape Rock A X 1\nshap Paper B Y 2").unwrap_err();
+        assert_eq!(error, GameError::InvalidLine(Location::new(2, 1, "shap Paper B Y 2")));
+
+        let error = Game::from_config("shape Rock A X 1\nshape Paper A Y 2").unwrap_err();
+        assert_eq!(error, GameError::DuplicateShape(Location::new(2, 13, "shape Paper A Y 2")));
+
+        let error = Game::from_config("shape Rock A X one").unwrap_err();
+        assert_eq!(error, GameError::InvalidLine(Location::new(1, 16, "shape Rock A X one")));
+
+        let error = Game::from_config("shape Rock A X 1\nshape Paper B Y 2\nbeats Paper Stone").unwrap_err();
+        assert_eq!(error, GameError::UnknownShape(Location::new(3, 13, "beats Paper Stone")));
+
+        let error = Game::from_config("shape Rock A X 1\nshape Paper B Y 2\nshape Scissors C Z 3\nbeats Paper Rock").unwrap_err();
+        assert_eq!(error, GameError::NotATournament(String::from("Rock"), String::from("Scissors")));
+
+        let error = Game::from_config("shape Rock A X 1\nshape Paper B Y 2").unwrap_err();
+        assert_eq!(error, GameError::ShapeCount(2));
+    }
+}
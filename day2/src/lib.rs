@@ -0,0 +1,197 @@
+pub mod game;
+pub mod scoring;
+pub mod solver;
+pub mod tournament;
+
+use std::fmt;
+
+use common::diagnostic::Location;
+
+pub use scoring::{Outcome, Shape, UnknownSymbol};
+
+/// Location of the puzzle input in the source tree, for benchmarks and tests.
+pub const PUZZLE_INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/puzzle_input.txt");
+/// The puzzle input embedded at build time, binaries fall back to it wherever they are installed.
+pub const PUZZLE_INPUT: &str = include_str!("../puzzle_input.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingColumn(Location),
+    UnknownOpponentShape(Location),
+    UnknownResponse(Location),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingColumn(location) => write!(f, "expected two columns separated by a space at {}", location),
+            ParseError::UnknownOpponentShape(location) => write!(f, "unknown opponent shape at {}", location),
+            ParseError::UnknownResponse(location) => write!(f, "unknown response at {}", location),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Meaning of the second column of the strategy guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// X, Y and Z are the shapes to play: rock, paper and scissors (part one).
+    Shapes,
+    /// X, Y and Z are the outcomes to reach: lose, draw and win (part two).
+    Outcomes,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 2] = [Strategy::Shapes, Strategy::Outcomes];
+
+    pub fn describe(self) -> &'static str {
+        match self {
+            Strategy::Shapes => "X/Y/Z are shapes",
+            Strategy::Outcomes => "X/Y/Z are outcomes",
+        }
+    }
+}
+
+/// A line of the strategy guide with both readings of the second column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuideEntry {
+    pub opponent: Shape,
+    /// The second column read as the shape to play.
+    pub shape: Shape,
+    /// The second column read as the outcome to reach.
+    pub outcome: Outcome,
+}
+
+impl GuideEntry {
+    /// Parses line `line_number` of a strategy guide.
+    pub fn parse(line_number: usize, line: &str) -> Result<GuideEntry, ParseError> {
+        let (opponent_symbol, response_symbol) = line.split_once(' ')
+            .ok_or(ParseError::MissingColumn(Location::new(line_number, line.chars().count() + 1, line)))?;
+        let opponent: Shape = opponent_symbol.parse()
+            .map_err(|_| ParseError::UnknownOpponentShape(Location::of(line_number, line, opponent_symbol)))?;
+        let unknown_response = |_| ParseError::UnknownResponse(Location::of(line_number, line, response_symbol));
+        let shape = Shape::from_response(response_symbol).map_err(unknown_response)?;
+        let outcome: Outcome = response_symbol.parse().map_err(unknown_response)?;
+        Ok(GuideEntry { opponent, shape, outcome })
+    }
+
+    pub fn round(self, strategy: Strategy) -> Round {
+        let response = match strategy {
+            Strategy::Shapes => self.shape,
+            Strategy::Outcomes => self.outcome.response_to(self.opponent),
+        };
+        Round { opponent: self.opponent, response }
+    }
+}
+
+/// A round as played: the opponent's shape and ours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Shape,
+    pub response: Shape,
+}
+
+impl Round {
+    /// Parses line `line_number` of a strategy guide, reading the second column according to `strategy`.
+    pub fn parse(line_number: usize, line: &str, strategy: Strategy) -> Result<Round, ParseError> {
+        Ok(GuideEntry::parse(line_number, line)?.round(strategy))
+    }
+
+    pub fn outcome(self) -> Outcome {
+        self.response.against(self.opponent)
+    }
+
+    pub fn score(self) -> i32 {
+        scoring::round_score(self.opponent, self.response)
+    }
+}
+
+pub fn get_score_from_line(line: &str, strategy: Strategy) -> Result<i32, ParseError>
+{
+    Ok(Round::parse(1, line, strategy)?.score())
+}
+
+/// Reads the guide once, both strategies can then be applied to it.
+pub fn parse_guide(contents: &str) -> Result<Vec<GuideEntry>, ParseError> {
+    contents.lines().enumerate().map(|(i, line)| GuideEntry::parse(i + 1, line)).collect()
+}
+
+pub fn parse_rounds(contents: &str, strategy: Strategy) -> Result<Vec<Round>, ParseError> {
+    Ok(parse_guide(contents)?.into_iter().map(|entry| entry.round(strategy)).collect())
+}
+
+pub fn total_score(guide: &[GuideEntry], strategy: Strategy) -> i32 {
+    guide.iter().map(|entry| entry.round(strategy).score()).sum()
+}
+
+pub fn calculate_total_score(contents: &str, strategy: Strategy) -> Result<i32, ParseError> {
+    Ok(total_score(&parse_guide(contents)?, strategy))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn end_to_end_example() {
+        let contents = include_str!("../example.txt");
+        assert_eq!(calculate_total_score(contents, Strategy::Shapes).unwrap(), 15);
+        assert_eq!(calculate_total_score(contents, Strategy::Outcomes).unwrap(), 12);
+    }
+
+    #[test]
+    fn guide_is_parsed_once_for_both_strategies() {
+        let guide = parse_guide(include_str!("../example.txt")).unwrap();
+        assert_eq!(guide[0], GuideEntry { opponent: Shape::Rock, shape: Shape::Paper, outcome: Outcome::Draw });
+        assert_eq!(guide[0].round(Strategy::Shapes).response, Shape::Paper);
+        assert_eq!(guide[0].round(Strategy::Outcomes).response, Shape::Rock);
+        assert_eq!((total_score(&guide, Strategy::Shapes), total_score(&guide, Strategy::Outcomes)), (15, 12));
+        let error = parse_guide("A Y\nB W").unwrap_err();
+        assert_eq!(error, ParseError::UnknownResponse(Location::new(2, 3, "B W")));
+    }
+
+    #[test]
+    fn score_from_line() {
+        assert_eq!(get_score_from_line("A Y", Strategy::Outcomes).unwrap(), 4);
+        assert_eq!(get_score_from_line("B X", Strategy::Outcomes).unwrap(), 1);
+        assert_eq!(get_score_from_line("C Z", Strategy::Outcomes).unwrap(), 7);
+    }
+
+    #[test]
+    fn score_from_line_with_shapes() {
+        assert_eq!(get_score_from_line("A Y", Strategy::Shapes).unwrap(), 8);
+        assert_eq!(get_score_from_line("B X", Strategy::Shapes).unwrap(), 1);
+        assert_eq!(get_score_from_line("C Z", Strategy::Shapes).unwrap(), 6);
+        assert_eq!(get_score_from_line("C X", Strategy::Shapes).unwrap(), 7);
+    }
+
+    #[test]
+    fn parse_round_with_either_strategy() {
+        let round = Round::parse(1, "A Y", Strategy::Shapes).unwrap();
+        assert_eq!(round, Round { opponent: Shape::Rock, response: Shape::Paper });
+        assert_eq!((round.outcome(), round.score()), (Outcome::Win, 8));
+
+        let round = Round::parse(1, "A Y", Strategy::Outcomes).unwrap();
+        assert_eq!(round, Round { opponent: Shape::Rock, response: Shape::Rock });
+        assert_eq!((round.outcome(), round.score()), (Outcome::Draw, 4));
+    }
+
+    #[test]
+    fn missing_column_is_an_error() {
+        let error = calculate_total_score("A Y\nB", Strategy::Outcomes).unwrap_err();
+        assert_eq!(error, ParseError::MissingColumn(Location::new(2, 2, "B")));
+    }
+
+    #[test]
+    fn unknown_symbols_are_errors() {
+        let error = calculate_total_score("D Q", Strategy::Shapes).unwrap_err();
+        assert_eq!(error, ParseError::UnknownOpponentShape(Location::new(1, 1, "D Q")));
+
+        let error = calculate_total_score("A Y\nA Q", Strategy::Outcomes).unwrap_err();
+        assert_eq!(error, ParseError::UnknownResponse(Location::new(2, 3, "A Q")));
+
+        let error = calculate_total_score("A X Y", Strategy::Shapes).unwrap_err();
+        assert_eq!(error, ParseError::UnknownResponse(Location::new(1, 3, "A X Y")));
+    }
+}
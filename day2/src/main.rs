@@ -1,64 +1,140 @@
-fn get_loose_icon_for(opponent_icon: &str) -> i8 {
-    return match opponent_icon {
-        "A" => 3,
-        "B" => 1,
-        "C" => 2,
-        _ => 0
-    };
-}
+use common::diagnostic::Report;
+use common::input::read_input;
+use day2::game::Game;
+use day2::solver::{self, parse_opponents};
+use day2::tournament::{round_robin, AlwaysRock, Entrant, FrequencyCounter, GuideBot, MarkovPredictor, RandomPlayer};
+use day2::{calculate_total_score, parse_rounds, Strategy, PUZZLE_INPUT};
 
-fn get_draw_icon_for(opponent_icon: &str) -> i8 {
-    return match opponent_icon {
-        "A" => 1,
-        "B" => 2,
-        "C" => 3,
-        _ => 0
-    };
-}
+const USAGE: &str = "usage: day2 [<input file|->] [--game <rps|rpsls|cyclic<n>|config file>]
+       day2 [<input file|->] --solve | --max-wins <k> | --no-repeat | --target <score>
+       day2 [<input file|->] --tournament [--rounds <n>] [--seed <n>]";
 
-fn get_win_icon_for(opponent_icon: &str) -> i8 {
-    return match opponent_icon {
-        "A" => 2,
-        "B" => 3,
-        "C" => 1,
-        _ => 0
-    };
+/// What the solver optimizes for the opponent column of the guide.
+enum Objective {
+    MaxScore,
+    MaxWins(usize),
+    NoRepeat,
+    Target(i32),
 }
 
-fn get_icon_score(opponent: &str, outcome: &str) -> i32 {
-    let my_icon = match outcome
-    {
-        "X" => get_loose_icon_for(opponent),
-        "Y" => get_draw_icon_for(opponent),
-        "Z" => get_win_icon_for(opponent),
-        _ => 0
-    };
-    return my_icon as i32;
+struct Args {
+    input: Option<String>,
+    game: Option<String>,
+    objective: Option<Objective>,
+    tournament: bool,
+    rounds: usize,
+    seed: u64,
 }
 
+fn parse_args(mut args: impl Iterator<Item=String>) -> Result<Args, String> {
+    let mut parsed = Args { input: None, game: None, objective: None, tournament: false, rounds: 1000, seed: 1 };
+    while let Some(arg) = args.next() {
+        let objective = match arg.as_str() {
+            "--solve" => Some(Objective::MaxScore),
+            "--no-repeat" => Some(Objective::NoRepeat),
+            "--max-wins" => {
+                let value = args.next().ok_or("missing value for max-wins")?;
+                Some(Objective::MaxWins(value.parse().map_err(|_| format!("invalid max-wins: '{}'", value))?))
+            }
+            "--target" => {
+                let value = args.next().ok_or("missing value for target")?;
+                Some(Objective::Target(value.parse().map_err(|_| format!("invalid target: '{}'", value))?))
+            }
+            _ => None,
+        };
+        if let Some(objective) = objective {
+            if parsed.objective.is_some() {
+                return Err(String::from("--solve, --max-wins, --no-repeat and --target are exclusive"));
+            }
+            parsed.objective = Some(objective);
+        } else if arg == "--game" {
+            parsed.game = Some(args.next().ok_or("missing value for game")?);
+        } else if arg == "--tournament" {
+            parsed.tournament = true;
+        } else if arg == "--rounds" {
+            let value = args.next().ok_or("missing value for rounds")?;
+            parsed.rounds = value.parse().map_err(|_| format!("invalid rounds: '{}'", value))?;
+        } else if arg == "--seed" {
+            let value = args.next().ok_or("missing value for seed")?;
+            parsed.seed = value.parse().map_err(|_| format!("invalid seed: '{}'", value))?;
+        } else if parsed.input.is_none() {
+            parsed.input = Some(arg);
+        } else {
+            return Err(format!("unexpected argument '{}'", arg));
+        }
+    }
+    if (parsed.objective.is_some() || parsed.tournament) && parsed.game.is_some() {
+        return Err(String::from("the solver and the tournament only play rock paper scissors"));
+    }
+    if parsed.objective.is_some() && parsed.tournament {
+        return Err(String::from("--tournament cannot be combined with the solver"));
+    }
+    Ok(parsed)
+}
 
-fn get_outcome_score(icon: &str) -> i32
-{
-    return match icon {
-        "X" => 0,
-        "Y" => 3,
-        "Z" => 6,
-        _ => 0
+fn solve(contents: &str, objective: Objective) -> Result<(), Report> {
+    let opponents = parse_opponents(contents)?;
+    let plan = match objective {
+        Objective::MaxScore => Some(solver::max_score(&opponents)),
+        Objective::MaxWins(k) => Some(solver::max_score_with_wins(&opponents, k)),
+        Objective::NoRepeat => Some(solver::max_score_without_repeats(&opponents)),
+        Objective::Target(target) => solver::exact_score(&opponents, target),
     };
+    match plan {
+        Some(plan) => println!("Score:\n{}\nResponses:\n{}", plan.score, plan.response_symbols()),
+        None => println!("No responses reach this score"),
+    }
+    Ok(())
 }
 
-
-fn get_score_from_line(line: &str) -> i32
-{
-    let split: Vec<&str> = line.split(" ").collect();
-    return get_icon_score(split[0], split[1]) + get_outcome_score(split[1]);
+/// Plays every bot against every other, the guide bot follows the guide read as outcomes.
+fn tournament(contents: &str, rounds: usize, seed: u64) -> Result<(), Report> {
+    let guide = parse_rounds(contents, Strategy::Outcomes)?;
+    let entrants = [
+        Entrant::new("rock", || Box::new(AlwaysRock)),
+        Entrant::new("random", move || Box::new(RandomPlayer::new(seed))),
+        Entrant::new("frequency", || Box::new(FrequencyCounter::default())),
+        Entrant::new("markov", || Box::new(MarkovPredictor::default())),
+        Entrant::new("guide", move || Box::new(GuideBot::new(&guide))),
+    ];
+    print!("{}", round_robin(&entrants, rounds));
+    Ok(())
 }
 
+/// A built in game, a cyclic game like `cyclic7` or a game read from a config file.
+fn load_game(name: &str) -> Result<Game, Report> {
+    if let Some(game) = Game::builtin(name) {
+        return Ok(game);
+    }
+    if let Some(Ok(n)) = name.strip_prefix("cyclic").map(str::parse) {
+        return Ok(Game::cyclic(n)?);
+    }
+    Ok(Game::from_config(&std::fs::read_to_string(name)?)?)
+}
 
-fn main() {
-    let contents = include_str!("../puzzle_input.txt");
-
-    let sum_score: i32 = contents.lines().map(|line| get_score_from_line(line)).sum();
-
-    println!("{}", sum_score);
+fn main() -> Result<(), Report> {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            std::process::exit(1);
+        }
+    };
+    let contents = read_input(args.input.as_deref(), PUZZLE_INPUT)?;
+    if let Some(objective) = args.objective {
+        return solve(&contents, objective);
+    }
+    if args.tournament {
+        return tournament(&contents, args.rounds, args.seed);
+    }
+    // rock paper scissors is scored like the solver and the tournament, other games by their definition
+    let game = args.game.as_deref().filter(|name| *name != "rps").map(load_game).transpose()?;
+    for strategy in Strategy::ALL {
+        let score = match &game {
+            Some(game) => game.total_score(&contents, strategy)?,
+            None => calculate_total_score(&contents, strategy)?,
+        };
+        println!("Total score ({}):\n{}", strategy.describe(), score);
+    }
+    Ok(())
 }
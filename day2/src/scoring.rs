@@ -0,0 +1,186 @@
+//! Rules of rock paper scissors, shared by the guide scorer, the solver and the tournament.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A symbol of the strategy guide that does not stand for a shape or an outcome.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnknownSymbol(pub String);
+
+impl fmt::Display for UnknownSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown symbol '{}'", self.0)
+    }
+}
+
+impl std::error::Error for UnknownSymbol {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    pub const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    /// The shape this one defeats.
+    pub fn beats(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        }
+    }
+
+    /// The shape that defeats this one.
+    pub fn loses_to(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+            Shape::Scissors => Shape::Rock,
+        }
+    }
+
+    pub fn score(self) -> i32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+
+    /// Outcome of playing this shape against `opponent`.
+    pub fn against(self, opponent: Shape) -> Outcome {
+        if self.beats() == opponent {
+            Outcome::Win
+        } else if self == opponent {
+            Outcome::Draw
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    /// Reads the second column as a shape: `X` for rock, `Y` for paper and `Z` for scissors.
+    pub fn from_response(symbol: &str) -> Result<Shape, UnknownSymbol> {
+        match symbol {
+            "X" => Ok(Shape::Rock),
+            "Y" => Ok(Shape::Paper),
+            "Z" => Ok(Shape::Scissors),
+            _ => Err(UnknownSymbol(symbol.to_string())),
+        }
+    }
+
+    /// Symbol of the shape in the second column, the inverse of [`Shape::from_response`].
+    pub fn response_symbol(self) -> char {
+        match self {
+            Shape::Rock => 'X',
+            Shape::Paper => 'Y',
+            Shape::Scissors => 'Z',
+        }
+    }
+}
+
+/// Reads the opponent's column: `A` for rock, `B` for paper and `C` for scissors.
+impl FromStr for Shape {
+    type Err = UnknownSymbol;
+
+    fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+        match symbol {
+            "A" => Ok(Shape::Rock),
+            "B" => Ok(Shape::Paper),
+            "C" => Ok(Shape::Scissors),
+            _ => Err(UnknownSymbol(symbol.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    pub fn score(self) -> i32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+
+    /// The shape to play against `opponent` to reach this outcome.
+    pub fn response_to(self, opponent: Shape) -> Shape {
+        match self {
+            Outcome::Lose => opponent.beats(),
+            Outcome::Draw => opponent,
+            Outcome::Win => opponent.loses_to(),
+        }
+    }
+}
+
+/// Reads the second column as an outcome: `X` to lose, `Y` to draw and `Z` to win.
+impl FromStr for Outcome {
+    type Err = UnknownSymbol;
+
+    fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+        match symbol {
+            "X" => Ok(Outcome::Lose),
+            "Y" => Ok(Outcome::Draw),
+            "Z" => Ok(Outcome::Win),
+            _ => Err(UnknownSymbol(symbol.to_string())),
+        }
+    }
+}
+
+/// Score of playing `response` against `opponent`: the shape score plus the outcome score.
+pub fn round_score(opponent: Shape, response: Shape) -> i32 {
+    response.score() + response.against(opponent).score()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shape_relations() {
+        for shape in Shape::ALL {
+            assert_eq!(shape.beats().loses_to(), shape);
+            assert_eq!(shape.against(shape.beats()), Outcome::Win);
+            assert_eq!(shape.against(shape.loses_to()), Outcome::Lose);
+            assert_eq!(shape.against(shape), Outcome::Draw);
+        }
+        assert_eq!(Shape::Rock.beats(), Shape::Scissors);
+    }
+
+    #[test]
+    fn outcome_response_reaches_outcome() {
+        for opponent in Shape::ALL {
+            for outcome in Outcome::ALL {
+                assert_eq!(outcome.response_to(opponent).against(opponent), outcome);
+            }
+        }
+    }
+
+    #[test]
+    fn parse_symbols() {
+        assert_eq!("B".parse::<Shape>(), Ok(Shape::Paper));
+        assert_eq!("X".parse::<Shape>(), Err(UnknownSymbol(String::from("X"))));
+        assert_eq!(Shape::from_response("Z"), Ok(Shape::Scissors));
+        assert_eq!(Shape::from_response(&Shape::Paper.response_symbol().to_string()), Ok(Shape::Paper));
+        assert_eq!("Z".parse::<Outcome>(), Ok(Outcome::Win));
+        assert_eq!("A".parse::<Outcome>(), Err(UnknownSymbol(String::from("A"))));
+    }
+
+    #[test]
+    fn round_scores() {
+        assert_eq!(round_score(Shape::Rock, Shape::Paper), 8);
+        assert_eq!(round_score(Shape::Paper, Shape::Rock), 1);
+        assert_eq!(round_score(Shape::Scissors, Shape::Scissors), 6);
+    }
+}
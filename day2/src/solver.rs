@@ -0,0 +1,273 @@
+//! Best responses to a known sequence of opponent shapes, with or without constraints.
+
+use common::diagnostic::Location;
+
+use crate::scoring::round_score;
+use crate::{Outcome, ParseError, Shape};
+
+/// Responses to every round and the total score they reach.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub responses: Vec<Shape>,
+    pub score: i32,
+}
+
+impl Plan {
+    fn new(opponents: &[Shape], responses: Vec<Shape>) -> Plan {
+        let score = total_score(opponents, &responses);
+        Plan { responses, score }
+    }
+
+    /// The responses as the second column of a strategy guide, e.g. `YZX`.
+    pub fn response_symbols(&self) -> String {
+        self.responses.iter().map(|shape| shape.response_symbol()).collect()
+    }
+}
+
+fn total_score(opponents: &[Shape], responses: &[Shape]) -> i32 {
+    opponents.iter().zip(responses).map(|(opponent, response)| round_score(*opponent, *response)).sum()
+}
+
+/// Reads the opponent column of a strategy guide, the second column is optional and ignored.
+pub fn parse_opponents(contents: &str) -> Result<Vec<Shape>, ParseError> {
+    contents.lines().enumerate()
+        .map(|(i, line)| {
+            let symbol = line.split(' ').next().unwrap();
+            symbol.parse().map_err(|_| ParseError::UnknownOpponentShape(Location::of(i + 1, line, symbol)))
+        })
+        .collect()
+}
+
+/// Best response to `opponent` among the shapes that satisfy `allowed`.
+fn best_response(opponent: Shape, allowed: impl Fn(Shape) -> bool) -> Option<Shape> {
+    Shape::ALL.into_iter().filter(|shape| allowed(*shape)).max_by_key(|shape| round_score(opponent, *shape))
+}
+
+pub fn max_score(opponents: &[Shape]) -> Plan {
+    let responses = opponents.iter().map(|opponent| best_response(*opponent, |_| true).unwrap()).collect();
+    Plan::new(opponents, responses)
+}
+
+/// Highest score with at most `max_wins` won rounds.
+///
+/// Rounds are independent, so starting from the best response that does not win every round,
+/// winning the rounds that gain the most is optimal.
+pub fn max_score_with_wins(opponents: &[Shape], max_wins: usize) -> Plan {
+    let mut responses: Vec<Shape> = opponents.iter()
+        .map(|opponent| best_response(*opponent, |shape| shape.against(*opponent) != Outcome::Win).unwrap())
+        .collect();
+    let mut gains: Vec<(i32, usize)> = opponents.iter().zip(&responses).enumerate()
+        .map(|(i, (opponent, response))| {
+            let gain = round_score(*opponent, opponent.loses_to()) - round_score(*opponent, *response);
+            (gain, i)
+        })
+        .filter(|(gain, _)| *gain > 0)
+        .collect();
+    gains.sort_by(|a, b| b.cmp(a));
+    for (_, i) in gains.into_iter().take(max_wins) {
+        responses[i] = opponents[i].loses_to();
+    }
+    Plan::new(opponents, responses)
+}
+
+/// Highest score without playing the same shape in two consecutive rounds.
+///
+/// `best[i][s]` is the highest score of rounds `0..=i` when round `i` is answered with shape `s`.
+pub fn max_score_without_repeats(opponents: &[Shape]) -> Plan {
+    if opponents.is_empty() {
+        return Plan { responses: vec![], score: 0 };
+    }
+    let mut best = vec![[0; 3]; opponents.len()];
+    let mut previous = vec![[0; 3]; opponents.len()];
+    for (s, shape) in Shape::ALL.iter().enumerate() {
+        best[0][s] = round_score(opponents[0], *shape);
+    }
+    for i in 1..opponents.len() {
+        for (s, shape) in Shape::ALL.iter().enumerate() {
+            let p = (0..3).filter(|p| *p != s).max_by_key(|p| best[i - 1][*p]).unwrap();
+            best[i][s] = best[i - 1][p] + round_score(opponents[i], *shape);
+            previous[i][s] = p;
+        }
+    }
+
+    let last = opponents.len() - 1;
+    let mut s = (0..3).max_by_key(|s| best[last][*s]).unwrap();
+    let mut responses = vec![Shape::ALL[s]; opponents.len()];
+    for i in (1..=last).rev() {
+        s = previous[i][s];
+        responses[i - 1] = Shape::ALL[s];
+    }
+    Plan::new(opponents, responses)
+}
+
+/// Adds the totals of `source` increased by `shift` to `target`.
+/// Both are bitsets of totals, word `w` holds the totals `64 * w` to `64 * w + 63`.
+fn shifted_or(target: &mut [u64], source: &[u64], shift: usize) {
+    let (words, bits) = (shift / 64, shift % 64);
+    for w in (words..target.len()).rev() {
+        let mut value = source[w - words] << bits;
+        if bits > 0 && w > words {
+            value |= source[w - words - 1] >> (64 - bits);
+        }
+        target[w] |= value;
+    }
+}
+
+fn contains(set: &[u64], total: usize) -> bool {
+    set[total / 64] & (1 << (total % 64)) != 0
+}
+
+/// Responses that reach exactly `target` points, `None` if no responses do.
+///
+/// `reachable[i]` holds the totals that rounds `0..i` can reach, at most `target`.
+pub fn exact_score(opponents: &[Shape], target: i32) -> Option<Plan> {
+    let target = usize::try_from(target).ok()?;
+    // no round scores more than 9, higher targets are unreachable and would only cost memory
+    if target > 9 * opponents.len() {
+        return None;
+    }
+    let words = target / 64 + 1;
+    let mut reachable = vec![vec![0u64; words]];
+    reachable[0][0] = 1;
+    for opponent in opponents {
+        let mut next = vec![0u64; words];
+        for shape in Shape::ALL {
+            shifted_or(&mut next, reachable.last().unwrap(), round_score(*opponent, shape) as usize);
+        }
+        reachable.push(next);
+    }
+    if !contains(&reachable[opponents.len()], target) {
+        return None;
+    }
+
+    let mut remaining = target;
+    let mut responses = vec![Shape::Rock; opponents.len()];
+    for i in (0..opponents.len()).rev() {
+        let shape = Shape::ALL.into_iter()
+            .find(|shape| {
+                let score = round_score(opponents[i], *shape) as usize;
+                score <= remaining && contains(&reachable[i], remaining - score)
+            })
+            .unwrap();
+        remaining -= round_score(opponents[i], shape) as usize;
+        responses[i] = shape;
+    }
+    Some(Plan::new(opponents, responses))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Vec<Shape> {
+        parse_opponents(include_str!("../example.txt")).unwrap()
+    }
+
+    /// Every sequence of responses to `opponents`.
+    fn all_responses(opponents: &[Shape]) -> Vec<Vec<Shape>> {
+        let mut sequences = vec![vec![]];
+        for _ in opponents {
+            sequences = sequences.iter()
+                .flat_map(|sequence| Shape::ALL.map(|shape| [sequence.clone(), vec![shape]].concat()))
+                .collect();
+        }
+        sequences
+    }
+
+    /// Every opponent sequence of up to 5 rounds.
+    fn small_cases() -> Vec<Vec<Shape>> {
+        (0..=5u32)
+            .flat_map(|len| (0..3usize.pow(len)).map(move |code| {
+                (0..len).map(|i| Shape::ALL[code / 3usize.pow(i) % 3]).collect()
+            }))
+            .collect()
+    }
+
+    #[test]
+    fn parse_opponents_ignores_second_column() {
+        assert_eq!(example(), vec![Shape::Rock, Shape::Paper, Shape::Scissors]);
+        assert_eq!(parse_opponents("C\nA X").unwrap(), vec![Shape::Scissors, Shape::Rock]);
+        let error = parse_opponents("A Y\nX A").unwrap_err();
+        assert_eq!(error, ParseError::UnknownOpponentShape(Location::new(2, 1, "X A")));
+    }
+
+    #[test]
+    fn max_score_wins_every_round() {
+        let plan = max_score(&example());
+        assert_eq!(plan.score, 8 + 9 + 7);
+        assert_eq!(plan.response_symbols(), "YZX");
+    }
+
+    #[test]
+    fn max_score_with_limited_wins() {
+        assert_eq!(max_score_with_wins(&example(), 0).score, 4 + 5 + 6);
+        let plan = max_score_with_wins(&example(), 1);
+        assert_eq!(plan.score, 8 + 5 + 6);
+        assert_eq!(max_score_with_wins(&example(), 5).score, 24);
+    }
+
+    #[test]
+    fn max_score_without_repeated_shapes() {
+        let opponents = vec![Shape::Rock; 3];
+        let plan = max_score_without_repeats(&opponents);
+        assert_eq!(plan.responses, vec![Shape::Paper, Shape::Rock, Shape::Paper]);
+        assert_eq!(plan.score, 20);
+        assert_eq!(max_score_without_repeats(&[]).score, 0);
+    }
+
+    #[test]
+    fn exact_score_reaches_target() {
+        for target in [6, 7, 10, 15, 20, 23, 24] {
+            let plan = exact_score(&example(), target).unwrap();
+            assert_eq!(plan.score, target);
+        }
+        // the rounds score 3, 4 or 8, then 1, 5 or 9, then 2, 6 or 7
+        assert_eq!(exact_score(&example(), 9), None);
+        assert_eq!(exact_score(&example(), 21), None);
+        assert_eq!(exact_score(&example(), 5), None);
+        assert_eq!(exact_score(&example(), 25), None);
+        assert_eq!(exact_score(&example(), -1), None);
+        assert_eq!(exact_score(&[], 0).unwrap().responses, vec![]);
+    }
+
+    #[test]
+    fn exact_score_handles_totals_beyond_one_word() {
+        let opponents = vec![Shape::Scissors; 40];
+        let plan = exact_score(&opponents, 40 * 7).unwrap();
+        assert_eq!(plan.responses, vec![Shape::Rock; 40]);
+        assert!(exact_score(&opponents, 40 * 7 + 1).is_none());
+    }
+
+    #[test]
+    fn exact_score_rejects_targets_above_the_maximum_at_once() {
+        let opponents = vec![Shape::Rock; 2500];
+        assert_eq!(exact_score(&opponents, i32::MAX), None);
+        assert_eq!(exact_score(&opponents, 9 * 2500 + 1), None);
+        assert_eq!(exact_score(&opponents, 8 * 2500).unwrap().score, 8 * 2500);
+    }
+
+    #[test]
+    fn solvers_match_brute_force() {
+        for opponents in small_cases() {
+            let sequences = all_responses(&opponents);
+            let score = |responses: &Vec<Shape>| total_score(&opponents, responses);
+            let wins = |responses: &Vec<Shape>| opponents.iter().zip(responses)
+                .filter(|(opponent, response)| response.against(**opponent) == Outcome::Win).count();
+
+            assert_eq!(max_score(&opponents).score, sequences.iter().map(score).max().unwrap());
+            for k in 0..3 {
+                let plan = max_score_with_wins(&opponents, k);
+                assert!(wins(&plan.responses) <= k);
+                assert_eq!(plan.score, sequences.iter().filter(|r| wins(r) <= k).map(score).max().unwrap());
+            }
+            let no_repeat = |r: &&Vec<Shape>| r.windows(2).all(|w| w[0] != w[1]);
+            let plan = max_score_without_repeats(&opponents);
+            assert!(no_repeat(&&plan.responses));
+            assert_eq!(plan.score, sequences.iter().filter(no_repeat).map(score).max().unwrap());
+            for target in 0..=9 * opponents.len() as i32 {
+                let reachable = sequences.iter().any(|r| score(r) == target);
+                assert_eq!(exact_score(&opponents, target).is_some(), reachable, "{:?} {}", opponents, target);
+            }
+        }
+    }
+}
@@ -0,0 +1,311 @@
+//! Round robin tournaments between rock paper scissors bots, scored like the strategy guide.
+
+use std::fmt;
+
+use common::rng::Rng;
+
+use crate::scoring::round_score;
+use crate::{Outcome, Round, Shape};
+
+/// A bot that picks its next shape from what its opponent played so far.
+/// Within a match the history grows by one shape per round, players may keep state about the part they saw.
+pub trait Player {
+    fn play(&mut self, opponent_history: &[Shape]) -> Shape;
+}
+
+pub struct AlwaysRock;
+
+impl Player for AlwaysRock {
+    fn play(&mut self, _: &[Shape]) -> Shape {
+        Shape::Rock
+    }
+}
+
+pub struct RandomPlayer {
+    rng: Rng,
+}
+
+impl RandomPlayer {
+    pub fn new(seed: u64) -> Self {
+        Self { rng: Rng::new(seed) }
+    }
+}
+
+impl Player for RandomPlayer {
+    fn play(&mut self, _: &[Shape]) -> Shape {
+        self.rng.pick(&Shape::ALL)
+    }
+}
+
+/// The shape that beats the most likely next shape, given how often each shape was seen.
+fn counter_to_most_frequent(counts: [usize; 3]) -> Shape {
+    let (likely, _) = Shape::ALL.iter().zip(counts).rev().max_by_key(|(_, count)| *count).unwrap();
+    likely.loses_to()
+}
+
+fn shape_index(shape: Shape) -> usize {
+    Shape::ALL.iter().position(|s| *s == shape).unwrap()
+}
+
+/// Beats the shape the opponent played most often.
+#[derive(Default)]
+pub struct FrequencyCounter {
+    counts: [usize; 3],
+    /// Length of the history already counted.
+    seen: usize,
+}
+
+impl Player for FrequencyCounter {
+    fn play(&mut self, opponent_history: &[Shape]) -> Shape {
+        for shape in &opponent_history[self.seen..] {
+            self.counts[shape_index(*shape)] += 1;
+        }
+        self.seen = opponent_history.len();
+        counter_to_most_frequent(self.counts)
+    }
+}
+
+/// Beats the shape that most often followed the opponent's last shape.
+#[derive(Default)]
+pub struct MarkovPredictor {
+    /// `transitions[a][b]` counts how often the opponent played `b` right after `a`.
+    transitions: [[usize; 3]; 3],
+    /// Length of the history already counted.
+    seen: usize,
+}
+
+impl Player for MarkovPredictor {
+    fn play(&mut self, opponent_history: &[Shape]) -> Shape {
+        for i in self.seen.max(1)..opponent_history.len() {
+            self.transitions[shape_index(opponent_history[i - 1])][shape_index(opponent_history[i])] += 1;
+        }
+        self.seen = opponent_history.len();
+        match opponent_history.last() {
+            Some(last) => counter_to_most_frequent(self.transitions[shape_index(*last)]),
+            None => counter_to_most_frequent([0; 3]),
+        }
+    }
+}
+
+/// Plays the responses of a strategy guide in order, starting over at its end.
+pub struct GuideBot {
+    responses: Vec<Shape>,
+    next: usize,
+}
+
+impl GuideBot {
+    pub fn new(rounds: &[Round]) -> Self {
+        Self { responses: rounds.iter().map(|round| round.response).collect(), next: 0 }
+    }
+}
+
+impl Player for GuideBot {
+    fn play(&mut self, _: &[Shape]) -> Shape {
+        if self.responses.is_empty() {
+            return Shape::Rock;
+        }
+        let shape = self.responses[self.next % self.responses.len()];
+        self.next += 1;
+        shape
+    }
+}
+
+/// A named way to create a fresh player for every match.
+pub struct Entrant {
+    pub name: String,
+    create: Box<dyn Fn() -> Box<dyn Player>>,
+}
+
+impl Entrant {
+    pub fn new(name: &str, create: impl Fn() -> Box<dyn Player> + 'static) -> Self {
+        Self { name: name.to_string(), create: Box::new(create) }
+    }
+}
+
+/// Rounds won, drawn and lost and the points scored by one player.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Record {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: i32,
+}
+
+impl Record {
+    fn add_round(&mut self, opponent: Shape, response: Shape) {
+        match response.against(opponent) {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Lose => self.losses += 1,
+        }
+        self.score += round_score(opponent, response);
+    }
+
+    fn add(&mut self, other: &Record) {
+        self.wins += other.wins;
+        self.draws += other.draws;
+        self.losses += other.losses;
+        self.score += other.score;
+    }
+
+    pub fn rounds(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    fn rate(&self, count: usize) -> f64 {
+        if self.rounds() == 0 { 0.0 } else { count as f64 / self.rounds() as f64 }
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        self.rate(self.wins)
+    }
+
+    pub fn draw_rate(&self) -> f64 {
+        self.rate(self.draws)
+    }
+
+    pub fn loss_rate(&self) -> f64 {
+        self.rate(self.losses)
+    }
+}
+
+/// Plays `rounds` rounds between two players, returns the records of both.
+pub fn play_match(a: &mut dyn Player, b: &mut dyn Player, rounds: usize) -> (Record, Record) {
+    let (mut history_a, mut history_b) = (vec![], vec![]);
+    let (mut record_a, mut record_b) = (Record::default(), Record::default());
+    for _ in 0..rounds {
+        let shape_a = a.play(&history_b);
+        let shape_b = b.play(&history_a);
+        record_a.add_round(shape_b, shape_a);
+        record_b.add_round(shape_a, shape_b);
+        history_a.push(shape_a);
+        history_b.push(shape_b);
+    }
+    (record_a, record_b)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    pub name: String,
+    pub record: Record,
+}
+
+/// Standings by descending score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Leaderboard {
+    pub standings: Vec<Standing>,
+}
+
+/// Every entrant plays a match of `rounds` rounds against every other entrant.
+pub fn round_robin(entrants: &[Entrant], rounds: usize) -> Leaderboard {
+    let mut records = vec![Record::default(); entrants.len()];
+    for i in 0..entrants.len() {
+        for j in i + 1..entrants.len() {
+            let (mut a, mut b) = ((entrants[i].create)(), (entrants[j].create)());
+            let (record_a, record_b) = play_match(a.as_mut(), b.as_mut(), rounds);
+            records[i].add(&record_a);
+            records[j].add(&record_b);
+        }
+    }
+    let mut standings: Vec<Standing> = entrants.iter().zip(records)
+        .map(|(entrant, record)| Standing { name: entrant.name.clone(), record })
+        .collect();
+    standings.sort_by(|a, b| b.record.score.cmp(&a.record.score).then_with(|| a.name.cmp(&b.name)));
+    Leaderboard { standings }
+}
+
+impl fmt::Display for Leaderboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:>4} {:<12} {:>8} {:>7} {:>7} {:>7}", "rank", "player", "score", "win", "draw", "loss")?;
+        for (rank, standing) in self.standings.iter().enumerate() {
+            let record = &standing.record;
+            writeln!(f, "{:>4} {:<12} {:>8} {:>6.1}% {:>6.1}% {:>6.1}%", rank + 1, standing.name, record.score,
+                     100.0 * record.win_rate(), 100.0 * record.draw_rate(), 100.0 * record.loss_rate())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse_rounds, Strategy};
+
+    fn guide_bot(guide: &str) -> GuideBot {
+        GuideBot::new(&parse_rounds(guide, Strategy::Shapes).unwrap())
+    }
+
+    #[test]
+    fn match_is_scored_like_the_guide() {
+        let (rock, paper) = play_match(&mut AlwaysRock, &mut guide_bot("A Y"), 10);
+        assert_eq!(rock, Record { wins: 0, draws: 0, losses: 10, score: 10 });
+        assert_eq!(paper, Record { wins: 10, draws: 0, losses: 0, score: 80 });
+    }
+
+    #[test]
+    fn guide_bot_repeats_the_guide() {
+        let mut bot = guide_bot("A Y\nB X\nC Z");
+        let shapes: Vec<Shape> = (0..4).map(|_| bot.play(&[])).collect();
+        assert_eq!(shapes, vec![Shape::Paper, Shape::Rock, Shape::Scissors, Shape::Paper]);
+    }
+
+    #[test]
+    fn frequency_counter_beats_always_rock() {
+        let (counter, rock) = play_match(&mut FrequencyCounter::default(), &mut AlwaysRock, 100);
+        assert_eq!(counter.wins, 100);
+        assert_eq!(rock.losses, 100);
+    }
+
+    #[test]
+    fn markov_predictor_learns_cycles() {
+        let (markov, cycle) = play_match(&mut MarkovPredictor::default(), &mut guide_bot("A X\nA Y\nA Z"), 300);
+        assert!(markov.wins > 290, "{:?}", markov);
+        // a cycle beats the counter of its most frequent shape a third of the time
+        let (counter, _) = play_match(&mut FrequencyCounter::default(), &mut guide_bot("A X\nA Y\nA Z"), 300);
+        assert!(counter.wins < markov.wins, "{:?} {:?}", counter, cycle);
+    }
+
+    #[test]
+    fn running_counts_match_a_rescan_of_the_history() {
+        let mut rng = RandomPlayer::new(5);
+        let history: Vec<Shape> = (0..200).map(|_| rng.play(&[])).collect();
+        let (mut counter, mut markov) = (FrequencyCounter::default(), MarkovPredictor::default());
+        for length in 0..=history.len() {
+            let seen = &history[..length];
+            let counts = Shape::ALL.map(|shape| seen.iter().filter(|s| **s == shape).count());
+            assert_eq!(counter.play(seen), counter_to_most_frequent(counts));
+            let followers = Shape::ALL.map(|shape| {
+                seen.windows(2).filter(|pair| Some(&pair[0]) == seen.last() && pair[1] == shape).count()
+            });
+            assert_eq!(markov.play(seen), counter_to_most_frequent(followers));
+        }
+    }
+
+    #[test]
+    fn random_player_is_seeded() {
+        let shapes = |seed| {
+            let mut player = RandomPlayer::new(seed);
+            (0..50).map(|_| player.play(&[])).collect::<Vec<_>>()
+        };
+        assert_eq!(shapes(3), shapes(3));
+        assert_ne!(shapes(3), shapes(4));
+        assert!(Shape::ALL.iter().all(|shape| shapes(3).contains(shape)));
+    }
+
+    #[test]
+    fn round_robin_leaderboard() {
+        let entrants = [
+            Entrant::new("rock", || Box::new(AlwaysRock)),
+            Entrant::new("frequency", || Box::new(FrequencyCounter::default())),
+            Entrant::new("random", || Box::new(RandomPlayer::new(1))),
+        ];
+        let leaderboard = round_robin(&entrants, 200);
+        assert_eq!(leaderboard.standings.len(), 3);
+        assert_eq!(leaderboard.standings[0].name, "frequency");
+        assert_eq!(leaderboard.standings[2].name, "rock");
+        assert!(leaderboard.standings.iter().all(|s| s.record.rounds() == 400));
+        assert!(leaderboard.standings.windows(2).all(|w| w[0].record.score >= w[1].record.score));
+        assert_eq!(round_robin(&entrants, 200), leaderboard);
+        assert!(leaderboard.to_string().lines().nth(1).unwrap().starts_with("   1 frequency"));
+    }
+}
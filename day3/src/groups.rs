@@ -0,0 +1,174 @@
+//! Groups of rucksacks of any size, with every item their rucksacks have in common.
+
+use std::fmt;
+
+use crate::{common_items, ItemSet, Rucksack};
+
+/// A group of consecutive rucksacks and the item types all of them hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    /// 1-based lines of the first and the last rucksack of the group.
+    pub lines: (usize, usize),
+    pub size: usize,
+    pub common: ItemSet,
+}
+
+impl Group {
+    /// The badge, if the rucksacks have exactly one item type in common.
+    pub fn badge(&self) -> Option<char> {
+        if self.common.len() == 1 { self.common.first() } else { None }
+    }
+
+    /// The common items and their priorities, by ascending priority.
+    pub fn common_priorities(&self) -> Vec<(char, u32)> {
+        self.common.priorities().map(|priority| (priority.item(), priority.value())).collect()
+    }
+}
+
+/// A group that does not have exactly one badge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Anomaly {
+    /// First line of a group whose rucksacks have no item in common.
+    NoBadge(usize),
+    /// First line of a group whose rucksacks have several items in common.
+    MultipleBadges(usize, ItemSet),
+    /// First line and size of a group at the end of the input with too few rucksacks.
+    IncompleteGroup(usize, usize),
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Anomaly::NoBadge(line) => write!(f, "group starting on line {} has no item in common", line),
+            Anomaly::MultipleBadges(line, items) => {
+                write!(f, "group starting on line {} has {} items in common: {}", line, items.len(), items.iter().collect::<String>())
+            }
+            Anomaly::IncompleteGroup(line, size) => {
+                write!(f, "group starting on line {} has only {} rucksack{}", line, size, if *size == 1 { "" } else { "s" })
+            }
+        }
+    }
+}
+
+/// Every group of `group_size` rucksacks and the groups without exactly one badge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupReport {
+    pub group_size: usize,
+    pub groups: Vec<Group>,
+    pub anomalies: Vec<Anomaly>,
+}
+
+impl GroupReport {
+    /// Sum of the priorities of the complete groups that have exactly one badge.
+    pub fn badge_priority_sum(&self) -> u32 {
+        self.groups.iter()
+            .filter(|group| group.size == self.group_size && group.badge().is_some())
+            .map(|group| group.common.priority_sum())
+            .sum()
+    }
+}
+
+/// What is wrong with `group`, whose rucksacks have the item types `common` in common.
+pub(crate) fn group_anomaly(group: &[Rucksack], group_size: usize, common: ItemSet) -> Option<Anomaly> {
+    let line = group[0].line;
+    if group.len() < group_size {
+        Some(Anomaly::IncompleteGroup(line, group.len()))
+    } else if common.is_empty() {
+        Some(Anomaly::NoBadge(line))
+    } else if common.len() > 1 {
+        Some(Anomaly::MultipleBadges(line, common))
+    } else {
+        None
+    }
+}
+
+/// Splits the rucksacks into groups of `group_size`, the last group may be smaller.
+///
+/// Panics if `group_size` is 0.
+pub fn report_groups(rucksacks: &[Rucksack], group_size: usize) -> GroupReport {
+    let mut groups = vec![];
+    let mut anomalies = vec![];
+    for chunk in rucksacks.chunks(group_size) {
+        let group = Group {
+            lines: (chunk[0].line, chunk[chunk.len() - 1].line),
+            size: chunk.len(),
+            common: common_items(chunk),
+        };
+        anomalies.extend(group_anomaly(chunk, group_size, group.common));
+        groups.push(group);
+    }
+    GroupReport { group_size, groups, anomalies }
+}
+
+impl fmt::Display for GroupReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for group in &self.groups {
+            let items: Vec<String> = group.common_priorities().iter()
+                .map(|(item, priority)| format!("{} ({})", item, priority))
+                .collect();
+            let items = if items.is_empty() { String::from("-") } else { items.join(", ") };
+            writeln!(f, "lines {:>5} - {:>5}: {}", group.lines.0, group.lines.1, items)?;
+        }
+        writeln!(f, "badges of complete groups of {}: {}", self.group_size, self.badge_priority_sum())?;
+        if !self.anomalies.is_empty() {
+            writeln!(f, "anomalies:")?;
+            for anomaly in &self.anomalies {
+                writeln!(f, "  {}", anomaly)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse_rucksacks;
+
+    fn report(contents: &str, group_size: usize) -> GroupReport {
+        report_groups(&parse_rucksacks(contents).unwrap(), group_size)
+    }
+
+    #[test]
+    fn example_groups_have_one_badge() {
+        let report = report(include_str!("../example.txt"), 3);
+        assert_eq!(report.groups.len(), 2);
+        assert_eq!(report.groups[1].lines, (4, 6));
+        assert_eq!(report.groups[0].common_priorities(), vec![('r', 18)]);
+        assert_eq!(report.badge_priority_sum(), 70);
+        assert!(report.anomalies.is_empty());
+    }
+
+    #[test]
+    fn group_size_is_configurable() {
+        let pairs = report(include_str!("../example.txt"), 2);
+        assert_eq!(pairs.groups.len(), 3);
+        assert!(pairs.groups.iter().all(|group| group.size == 2));
+        let all = report(include_str!("../example.txt"), 6);
+        assert_eq!(all.groups[0].lines, (1, 6));
+    }
+
+    #[test]
+    fn anomalies_are_reported() {
+        let report = report("abab\nabcc\nbaba\nxyxy\nyzyz\nzxzx\nabba", 3);
+        assert_eq!(report.anomalies, vec![
+            Anomaly::MultipleBadges(1, "ab".parse().unwrap()),
+            Anomaly::NoBadge(4),
+            Anomaly::IncompleteGroup(7, 1),
+        ]);
+        assert_eq!(report.groups[0].common_priorities(), vec![('a', 1), ('b', 2)]);
+        assert_eq!(report.badge_priority_sum(), 0);
+        let text = report.to_string();
+        assert!(text.contains("lines     4 -     6: -"), "{}", text);
+        assert!(text.contains("  group starting on line 1 has 2 items in common: ab"), "{}", text);
+        assert!(text.contains("  group starting on line 7 has only 1 rucksack\n"), "{}", text);
+    }
+
+    #[test]
+    fn trailing_partial_group_has_no_badge() {
+        let report = report("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nabcr\nrxyz", 3);
+        assert_eq!(report.groups[1].badge(), Some('r'));
+        assert_eq!(report.anomalies, vec![Anomaly::IncompleteGroup(4, 2)]);
+        assert_eq!(report.badge_priority_sum(), 18);
+    }
+}
@@ -0,0 +1,124 @@
+//! Sets of item types as a bit mask, bit `p` is set if the set holds the item of priority `p`.
+
+use std::str::FromStr;
+
+use crate::{NotAnItem, Priority};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItemSet(u64);
+
+fn bit(item: char) -> Result<u64, NotAnItem> {
+    Ok(1 << Priority::try_from(item)?.value())
+}
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    pub fn insert(&mut self, item: char) -> Result<(), NotAnItem> {
+        self.0 |= bit(item)?;
+        Ok(())
+    }
+
+    pub fn contains(self, item: char) -> bool {
+        bit(item).is_ok_and(|bit| self.0 & bit != 0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The priorities of the items, ascending.
+    pub fn priorities(self) -> impl Iterator<Item=Priority> {
+        let mut mask = self.0;
+        std::iter::from_fn(move || {
+            if mask == 0 {
+                return None;
+            }
+            let value = mask.trailing_zeros();
+            mask &= mask - 1;
+            Priority::new(value)
+        })
+    }
+
+    /// The items by ascending priority.
+    pub fn iter(self) -> impl Iterator<Item=char> {
+        self.priorities().map(Priority::item)
+    }
+
+    /// The item of lowest priority.
+    pub fn first(self) -> Option<char> {
+        self.iter().next()
+    }
+
+    pub fn priority_sum(self) -> u32 {
+        self.priorities().map(Priority::value).sum()
+    }
+}
+
+impl FromStr for ItemSet {
+    type Err = NotAnItem;
+
+    fn from_str(items: &str) -> Result<Self, Self::Err> {
+        let mut set = ItemSet::EMPTY;
+        for item in items.chars() {
+            set.insert(item)?;
+        }
+        Ok(set)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(items: &str) -> ItemSet {
+        items.parse().unwrap()
+    }
+
+    #[test]
+    fn from_str_collects_item_types() {
+        let items = set("vJrwpWtwJgWr");
+        assert_eq!(items.len(), 8);
+        assert!(items.contains('J'));
+        assert!(!items.contains('j'));
+        assert!(!items.contains('1'));
+        assert_eq!("ab1".parse::<ItemSet>(), Err(NotAnItem('1')));
+        assert!(set("").is_empty());
+    }
+
+    #[test]
+    fn intersection_and_union() {
+        assert_eq!(set("abcZ").intersection(set("cdZ")), set("Zc"));
+        assert_eq!(set("ab").union(set("bC")), set("abC"));
+        assert!(set("ab").intersection(set("AB")).is_empty());
+    }
+
+    #[test]
+    fn iteration_is_by_priority() {
+        let items: String = set("ZazA").iter().collect();
+        assert_eq!(items, "azAZ");
+        assert_eq!(set("Zb").first(), Some('b'));
+        assert_eq!(ItemSet::EMPTY.first(), None);
+    }
+
+    #[test]
+    fn priority_sum_of_single_items() {
+        for item in ('a'..='z').chain('A'..='Z') {
+            let priority = Priority::try_from(item).unwrap();
+            assert_eq!(set(&item.to_string()).priorities().collect::<Vec<_>>(), vec![priority]);
+            assert_eq!(set(&item.to_string()).priority_sum(), priority.value());
+        }
+    }
+}
@@ -0,0 +1,229 @@
+pub mod groups;
+pub mod item_set;
+pub mod priority;
+pub mod reorganise;
+
+use std::fmt;
+
+use common::diagnostic::Location;
+
+use groups::{group_anomaly, Anomaly};
+pub use item_set::ItemSet;
+pub use priority::{NotAnItem, Priority};
+
+/// Location of the puzzle input in the source tree, for benchmarks and tests.
+pub const PUZZLE_INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/puzzle_input.txt");
+/// The puzzle input embedded at build time, binaries fall back to it wherever they are installed.
+pub const PUZZLE_INPUT: &str = include_str!("../puzzle_input.txt");
+
+/// Number of elves that share a badge in the puzzle.
+pub const GROUP_SIZE: usize = 3;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// An item that is not a letter `a-z` or `A-Z`.
+    InvalidItem(Location, char),
+    UnevenCompartments(Location),
+    /// Line of a rucksack whose compartments have no item in common.
+    NoSharedItem(usize),
+    /// A group without exactly one badge.
+    BadGroup(Anomaly),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidItem(location, item) => {
+                write!(f, "item {:?} is not a letter a-z or A-Z at {}", item, location)
+            }
+            ParseError::UnevenCompartments(location) => {
+                write!(f, "rucksack has an odd number of items ({}) at {}", location.snippet.len(), location)
+            }
+            ParseError::NoSharedItem(line) => write!(f, "compartments have no item in common on line {}", line),
+            ParseError::BadGroup(anomaly) => write!(f, "{}", anomaly),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    /// 1-based line of the rucksack in the input.
+    pub line: usize,
+    pub items: String,
+    compartment_sets: (ItemSet, ItemSet),
+}
+
+impl Rucksack {
+    /// The two halves of the items.
+    pub fn compartments(&self) -> (&str, &str) {
+        self.items.split_at(self.items.len() / 2)
+    }
+
+    /// The item types of both compartments.
+    pub fn item_set(&self) -> ItemSet {
+        self.compartment_sets.0.union(self.compartment_sets.1)
+    }
+
+    /// The item types that are in both compartments.
+    pub fn shared_items(&self) -> ItemSet {
+        self.compartment_sets.0.intersection(self.compartment_sets.1)
+    }
+
+    /// The lowest priority item that is in both compartments.
+    pub fn shared_item(&self) -> Option<char> {
+        self.shared_items().first()
+    }
+}
+
+fn parse_rucksack(line_number: usize, line: &str) -> Result<Rucksack, ParseError> {
+    if let Some((column, item)) = line.chars().enumerate().find(|(_, c)| Priority::try_from(*c).is_err()) {
+        return Err(ParseError::InvalidItem(Location::new(line_number, column + 1, line), item));
+    }
+    if line.len() % 2 == 1 {
+        return Err(ParseError::UnevenCompartments(Location::new(line_number, line.len() + 1, line)));
+    }
+    let (first, second) = line.split_at(line.len() / 2);
+    // only letters are left, so both halves are valid item sets
+    let compartment_sets = (first.parse::<ItemSet>().unwrap(), second.parse::<ItemSet>().unwrap());
+    Ok(Rucksack { line: line_number, items: line.to_string(), compartment_sets })
+}
+
+/// Reads one rucksack per line, every rucksack has two compartments of the same size.
+pub fn parse_rucksacks(contents: &str) -> Result<Vec<Rucksack>, ParseError> {
+    contents.lines().enumerate().map(|(i, line)| parse_rucksack(i + 1, line)).collect()
+}
+
+/// Sum of the priorities of the items in both compartments of each rucksack.
+pub fn compartment_priority_sum(rucksacks: &[Rucksack]) -> Result<u32, ParseError> {
+    rucksacks.iter()
+        .map(|rucksack| rucksack.shared_items().priorities().next().map(Priority::value).ok_or(ParseError::NoSharedItem(rucksack.line)))
+        .sum()
+}
+
+/// The item types common to all `rucksacks`.
+pub(crate) fn common_items(rucksacks: &[Rucksack]) -> ItemSet {
+    rucksacks.iter().map(Rucksack::item_set).reduce(ItemSet::intersection).unwrap_or_default()
+}
+
+/// Sum of the priorities of the badges, the item common to each group of `group_size` rucksacks.
+/// A group with no badge, several badges or too few rucksacks is an error.
+///
+/// Panics if `group_size` is 0.
+pub fn badge_priority_sum(rucksacks: &[Rucksack], group_size: usize) -> Result<u32, ParseError> {
+    rucksacks.chunks(group_size)
+        .map(|group| {
+            let common = common_items(group);
+            match group_anomaly(group, group_size, common) {
+                Some(anomaly) => Err(ParseError::BadGroup(anomaly)),
+                None => Ok(common.priority_sum()),
+            }
+        })
+        .sum()
+}
+
+pub fn calculate_compartment_sum(contents: &str) -> Result<u32, ParseError> {
+    compartment_priority_sum(&parse_rucksacks(contents)?)
+}
+
+pub fn calculate_badge_sum(contents: &str) -> Result<u32, ParseError> {
+    badge_priority_sum(&parse_rucksacks(contents)?, GROUP_SIZE)
+}
+
+pub fn calculate_value_from_group(group: &[&str]) -> Option<u32> {
+    get_common_char(group).and_then(|item| Priority::try_from(item).ok()).map(Priority::value)
+}
+
+fn get_common_char(group: &[&str]) -> Option<char> {
+    let mut common = group.first()?.parse::<ItemSet>().ok()?;
+    for items in &group[1..] {
+        common = common.intersection(items.parse().ok()?);
+    }
+    common.first()
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn end_to_end_example_compartments() {
+        let contents = include_str!("../example.txt");
+        assert_eq!(calculate_compartment_sum(contents).unwrap(), 157);
+    }
+
+    #[test]
+    fn end_to_end_example_badges() {
+        let contents = include_str!("../example.txt");
+        assert_eq!(calculate_badge_sum(contents).unwrap(), 70);
+    }
+
+    #[test]
+    fn shared_item_of_compartments() {
+        let rucksacks = parse_rucksacks(include_str!("../example.txt")).unwrap();
+        let shared: String = rucksacks.iter().map(|r| r.shared_item().unwrap()).collect();
+        assert_eq!(shared, "pLPvts");
+        assert_eq!(rucksacks[0].compartments(), ("vJrwpWtwJgWr", "hcsFMMfFFhFp"));
+    }
+
+    #[test]
+    fn common_char_of_group_uses_all_three_rucksacks() {
+        assert_eq!(get_common_char(&["abc", "bcd", "cde"]), Some('c'));
+        assert_eq!(get_common_char(&["abc", "bcd", "def"]), None);
+        assert_eq!(calculate_value_from_group(&["aZ", "Zb", "cZ"]), Some(52));
+    }
+
+    #[test]
+    fn invalid_item_is_an_error() {
+        let error = parse_rucksacks("abab\nab1b").unwrap_err();
+        assert_eq!(error, ParseError::InvalidItem(Location::new(2, 3, "ab1b"), '1'));
+        assert!(error.to_string().starts_with("item '1' is not a letter a-z or A-Z at line 2, column 3:"));
+        assert_eq!(parse_rucksacks("ab-b"), Err(ParseError::InvalidItem(Location::new(1, 3, "ab-b"), '-')));
+        // columns count characters, not bytes
+        assert_eq!(parse_rucksacks("éaéb"), Err(ParseError::InvalidItem(Location::new(1, 1, "éaéb"), 'é')));
+        assert_eq!(parse_rucksacks("abéb"), Err(ParseError::InvalidItem(Location::new(1, 3, "abéb"), 'é')));
+        // the letter check comes before the length check
+        assert_eq!(parse_rucksacks("ab1"), Err(ParseError::InvalidItem(Location::new(1, 3, "ab1"), '1')));
+    }
+
+    #[test]
+    fn uneven_rucksack_is_an_error() {
+        let error = parse_rucksacks("abab\nabc").unwrap_err();
+        assert_eq!(error, ParseError::UnevenCompartments(Location::new(2, 4, "abc")));
+        assert!(error.to_string().starts_with("rucksack has an odd number of items (3) at line 2, column 4:"));
+    }
+
+    #[test]
+    fn missing_common_items_are_errors() {
+        assert_eq!(calculate_compartment_sum("aa\nab"), Err(ParseError::NoSharedItem(2)));
+        assert_eq!(calculate_badge_sum("aa\nab\nbb"), Err(ParseError::BadGroup(Anomaly::NoBadge(1))));
+        assert_eq!(calculate_badge_sum("aa\nab\naa\nbb"), Err(ParseError::BadGroup(Anomaly::IncompleteGroup(4, 1))));
+        let error = calculate_badge_sum("abab\nabcc\nbaba").unwrap_err();
+        assert_eq!(error, ParseError::BadGroup(Anomaly::MultipleBadges(1, "ab".parse().unwrap())));
+        assert_eq!(error.to_string(), "group starting on line 1 has 2 items in common: ab");
+    }
+
+    fn char_to_value(c: char) -> u32 {
+        Priority::try_from(c).unwrap().value()
+    }
+
+    #[test]
+    fn lower_case_char_to_value_test() {
+        assert_eq!(char_to_value('a'), 1);
+        assert_eq!(char_to_value('b'), 2);
+        assert_eq!(char_to_value('p'), 16);
+        assert_eq!(char_to_value('v'), 22);
+        assert_eq!(char_to_value('t'), 20);
+        assert_eq!(char_to_value('s'), 19);
+    }
+
+    #[test]
+    fn upper_case_char_to_value_test() {
+        assert_eq!(char_to_value('L'), 38);
+        assert_eq!(char_to_value('A'), 27);
+        assert_eq!(char_to_value('B'), 28);
+        assert_eq!(char_to_value('P'), 42);
+    }
+}
@@ -1,59 +1,73 @@
-fn main() {
-    let contents = include_str!("../puzzle_input.txt");
-    let sum: u32 = contents
-        .lines()
-        .collect::<Vec<_>>()
-        .chunks(3)
-        .map(|x| calculate_value_from_group(x)).sum();
+use common::diagnostic::Report;
+use common::input::read_input;
+use day3::groups::report_groups;
+use day3::reorganise::reorganise;
+use day3::{badge_priority_sum, compartment_priority_sum, parse_rucksacks, GROUP_SIZE, PUZZLE_INPUT};
 
-    println!("calculated value: {}", sum);
-}
-
-fn calculate_value_from_group(group: &[&str]) -> u32 {
-    let common_char = get_common_char(group);
-    return char_to_value(common_char);
-}
+const USAGE: &str = "usage: day3 [<input file|->] [--group-size <n>] [--report]
+       day3 [<input file|->] --reorganise <capacity>";
 
-fn char_to_value(c: char) -> u32
-{
-    if c.is_uppercase() {
-        return c as u32 - 38;
-    }
-    return c as u32 - 96;
+struct Args {
+    input: Option<String>,
+    group_size: usize,
+    report: bool,
+    /// Items per compartment when reorganising.
+    capacity: Option<usize>,
 }
 
-fn get_common_char(group: &[&str]) -> char {
-    for c in group[0].chars() {
-        let contained_in_second = group[1].contains(c);
-        let contained_in_third = group[3].contains(c);
-        if contained_in_second && contained_in_third {
-            return c;
+fn parse_args(mut args: impl Iterator<Item=String>) -> Result<Args, String> {
+    let mut parsed = Args { input: None, group_size: GROUP_SIZE, report: false, capacity: None };
+    while let Some(arg) = args.next() {
+        if arg == "--group-size" {
+            let value = args.next().ok_or("missing value for group-size")?;
+            parsed.group_size = match value.parse() {
+                Ok(size) if size > 0 => size,
+                _ => return Err(format!("invalid group-size: '{}'", value)),
+            };
+        } else if arg == "--reorganise" {
+            let value = args.next().ok_or("missing value for reorganise")?;
+            parsed.capacity = Some(value.parse().map_err(|_| format!("invalid capacity: '{}'", value))?);
+        } else if arg == "--report" {
+            parsed.report = true;
+        } else if parsed.input.is_none() {
+            parsed.input = Some(arg);
+        } else {
+            return Err(format!("unexpected argument '{}'", arg));
         }
     }
-
-    panic!("no common char found")
+    if parsed.capacity.is_some() && parsed.report {
+        return Err(String::from("--reorganise and --report are exclusive"));
+    }
+    Ok(parsed)
 }
 
-
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn lower_case_char_to_value_test() {
-        assert_eq!(char_to_value('a'), 1);
-        assert_eq!(char_to_value('b'), 2);
-        assert_eq!(char_to_value('p'), 16);
-        assert_eq!(char_to_value('v'), 22);
-        assert_eq!(char_to_value('t'), 20);
-        assert_eq!(char_to_value('s'), 19);
+fn main() -> Result<(), Report> {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            std::process::exit(1);
+        }
+    };
+    let contents = read_input(args.input.as_deref(), PUZZLE_INPUT)?;
+    let rucksacks = parse_rucksacks(&contents)?;
+    if let Some(capacity) = args.capacity {
+        let plan = reorganise(&rucksacks, capacity);
+        for m in &plan.moves {
+            println!("{}", m);
+        }
+        println!("moved items: {}", plan.item_count());
+        for line in &plan.unfixable {
+            println!("rucksack on line {} cannot be fixed with {} items per compartment", line, capacity);
+        }
+        println!("items in both compartments: {}", plan.priority_sum);
+        return Ok(());
     }
-
-    #[test]
-    fn upper_case_char_to_value_test() {
-        assert_eq!(char_to_value('L'), 38);
-        assert_eq!(char_to_value('A'), 27);
-        assert_eq!(char_to_value('B'), 28);
-        assert_eq!(char_to_value('P'), 42);
+    if args.report {
+        print!("{}", report_groups(&rucksacks, args.group_size));
+        return Ok(());
     }
-}
\ No newline at end of file
+    println!("items in both compartments: {}", compartment_priority_sum(&rucksacks)?);
+    println!("badges: {}", badge_priority_sum(&rucksacks, args.group_size)?);
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! Priorities of item types, `a-z` are 1 to 26 and `A-Z` are 27 to 52.
+
+use std::fmt;
+
+/// A character that is not an item type, only `a-z` and `A-Z` are.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NotAnItem(pub char);
+
+impl fmt::Display for NotAnItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not an item type", self.0)
+    }
+}
+
+impl std::error::Error for NotAnItem {}
+
+/// The priority of an item type, always in `1..=52`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Priority(u8);
+
+impl Priority {
+    pub const MAX: u32 = 52;
+
+    /// The priority with this value, `None` outside `1..=52`.
+    pub fn new(value: u32) -> Option<Priority> {
+        (1..=Self::MAX).contains(&value).then_some(Priority(value as u8))
+    }
+
+    pub fn value(self) -> u32 {
+        self.0 as u32
+    }
+
+    /// The item type of this priority.
+    pub fn item(self) -> char {
+        if self.0 <= 26 {
+            char::from(b'a' + self.0 - 1)
+        } else {
+            char::from(b'A' + self.0 - 27)
+        }
+    }
+}
+
+impl TryFrom<char> for Priority {
+    type Error = NotAnItem;
+
+    fn try_from(item: char) -> Result<Self, Self::Error> {
+        match item {
+            'a'..='z' => Ok(Priority(item as u8 - b'a' + 1)),
+            'A'..='Z' => Ok(Priority(item as u8 - b'A' + 27)),
+            _ => Err(NotAnItem(item)),
+        }
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn only_letters_have_priorities() {
+        for item in ['0', '9', '!', ' ', '[', '`', '{', 'é', 'ß', 'Ж'] {
+            assert_eq!(Priority::try_from(item), Err(NotAnItem(item)));
+        }
+    }
+
+    #[test]
+    fn priorities_round_trip() {
+        for (value, item) in ('a'..='z').chain('A'..='Z').enumerate() {
+            let priority = Priority::try_from(item).unwrap();
+            assert_eq!(priority.value(), value as u32 + 1);
+            assert_eq!(priority.item(), item);
+            assert_eq!(Priority::new(priority.value()), Some(priority));
+        }
+        assert_eq!(Priority::new(0), None);
+        assert_eq!(Priority::new(53), None);
+    }
+}
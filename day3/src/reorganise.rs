@@ -0,0 +1,288 @@
+//! Moves items between the compartments of each rucksack until no item type is in both.
+//!
+//! Every item type of a rucksack ends up in one compartment. A type in both compartments has
+//! to be moved out of one of them, a type in one compartment may move as a whole to make room.
+//! Each rucksack is planned on its own: `cost[s]` is the fewest items moved for a net shift of
+//! `s` items from the first to the second compartment, the shift decides whether both
+//! compartments stay within the capacity.
+
+use std::fmt;
+
+use crate::{Priority, Rucksack};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compartment {
+    First,
+    Second,
+}
+
+impl fmt::Display for Compartment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Compartment::First => write!(f, "first"),
+            Compartment::Second => write!(f, "second"),
+        }
+    }
+}
+
+/// Moves `count` items of one type to the other compartment of the rucksack on `line`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub line: usize,
+    pub item: char,
+    pub count: usize,
+    pub from: Compartment,
+}
+
+impl Move {
+    pub fn to(&self) -> Compartment {
+        match self.from {
+            Compartment::First => Compartment::Second,
+            Compartment::Second => Compartment::First,
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: move {} x {} from the {} to the {} compartment", self.line, self.count, self.item, self.from, self.to())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reorganisation {
+    pub moves: Vec<Move>,
+    /// Lines of the rucksacks that cannot be fixed within the capacity, they are left as they are.
+    pub unfixable: Vec<usize>,
+    /// Sum of the priorities of the item types still in both compartments after the moves.
+    pub priority_sum: u32,
+}
+
+impl Reorganisation {
+    /// Number of items moved.
+    pub fn item_count(&self) -> usize {
+        self.moves.iter().map(|m| m.count).sum()
+    }
+}
+
+/// How many items of one type each compartment holds.
+struct ItemType {
+    item: char,
+    first: usize,
+    second: usize,
+}
+
+impl ItemType {
+    /// The net shift, the items moved and the compartment they leave, for leaving the type where it is
+    /// if it is in one compartment only and for moving it out of either compartment.
+    fn options(&self) -> Vec<(isize, usize, Option<Compartment>)> {
+        let mut options = vec![];
+        if self.first == 0 || self.second == 0 {
+            options.push((0, 0, None));
+        }
+        if self.first > 0 {
+            options.push((self.first as isize, self.first, Some(Compartment::First)));
+        }
+        if self.second > 0 {
+            options.push((-(self.second as isize), self.second, Some(Compartment::Second)));
+        }
+        options
+    }
+}
+
+fn item_types(rucksack: &Rucksack) -> Vec<ItemType> {
+    let (first, second) = rucksack.compartments();
+    let mut counts = [(0, 0); Priority::MAX as usize + 1];
+    for item in first.chars() {
+        counts[Priority::try_from(item).unwrap().value() as usize].0 += 1;
+    }
+    for item in second.chars() {
+        counts[Priority::try_from(item).unwrap().value() as usize].1 += 1;
+    }
+    counts.iter().enumerate()
+        .filter(|(_, (first, second))| first + second > 0)
+        .map(|(value, (first, second))| {
+            ItemType { item: Priority::new(value as u32).unwrap().item(), first: *first, second: *second }
+        })
+        .collect()
+}
+
+/// The fewest item moves that leave no type in both compartments of `rucksack`, with at most
+/// `capacity` items in each compartment. `None` if there are none.
+pub fn reorganise_rucksack(rucksack: &Rucksack, capacity: usize) -> Option<Vec<Move>> {
+    let types = item_types(rucksack);
+    let (first, second) = rucksack.compartments();
+    let (first, second) = (first.len() as isize, second.len() as isize);
+    // shifts go from -second to first, index `s + second`
+    let width = (first + second + 1) as usize;
+    let mut cost: Vec<Option<usize>> = vec![None; width];
+    cost[second as usize] = Some(0);
+    let mut choices = vec![];
+    for item_type in &types {
+        let mut next = vec![None; width];
+        let mut choice = vec![0; width];
+        for (index, moved) in cost.iter().enumerate() {
+            let Some(moved) = moved else { continue };
+            for (option, (shift, count, _)) in item_type.options().into_iter().enumerate() {
+                let target = (index as isize + shift) as usize;
+                if next[target].is_none_or(|best| moved + count < best) {
+                    next[target] = Some(moved + count);
+                    choice[target] = option;
+                }
+            }
+        }
+        cost = next;
+        choices.push(choice);
+    }
+
+    let capacity = capacity as isize;
+    let (lowest, highest) = (first - capacity, capacity - second);
+    let (mut index, _) = cost.iter().enumerate()
+        .filter(|(index, _)| (lowest..=highest).contains(&(*index as isize - second)))
+        .filter_map(|(index, moved)| moved.map(|moved| (index, moved)))
+        .min_by_key(|(index, moved)| (*moved, (*index as isize - second).abs()))?;
+
+    let mut moves = vec![];
+    for (item_type, choice) in types.iter().zip(&choices).rev() {
+        let (shift, count, from) = item_type.options()[choice[index]];
+        if let Some(from) = from {
+            moves.push(Move { line: rucksack.line, item: item_type.item, count, from });
+        }
+        index = (index as isize - shift) as usize;
+    }
+    moves.reverse();
+    Some(moves)
+}
+
+/// Plans the moves for every rucksack, with at most `capacity` items in each compartment.
+pub fn reorganise(rucksacks: &[Rucksack], capacity: usize) -> Reorganisation {
+    let mut moves = vec![];
+    let mut unfixable = vec![];
+    let mut priority_sum = 0;
+    for rucksack in rucksacks {
+        match reorganise_rucksack(rucksack, capacity) {
+            Some(rucksack_moves) => moves.extend(rucksack_moves),
+            None => {
+                unfixable.push(rucksack.line);
+                priority_sum += rucksack.shared_items().priority_sum();
+            }
+        }
+    }
+    Reorganisation { moves, unfixable, priority_sum }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse_rucksacks, ItemSet};
+
+    fn rucksack(items: &str) -> Rucksack {
+        parse_rucksacks(items).unwrap().remove(0)
+    }
+
+    /// Fewest items moved over every placement of every item type.
+    fn brute_force(rucksack: &Rucksack, capacity: usize) -> Option<usize> {
+        let types = item_types(rucksack);
+        let mut best: Option<usize> = None;
+        let mut choice = vec![0; types.len()];
+        loop {
+            let (mut first, mut second, mut moved) = (0, 0, 0);
+            for (item_type, option) in types.iter().zip(&choice) {
+                let (_, count, from) = item_type.options()[*option];
+                moved += count;
+                match from {
+                    None => {
+                        first += item_type.first;
+                        second += item_type.second;
+                    }
+                    Some(Compartment::First) => second += item_type.first + item_type.second,
+                    Some(Compartment::Second) => first += item_type.first + item_type.second,
+                }
+            }
+            if first <= capacity && second <= capacity {
+                best = Some(best.map_or(moved, |best| best.min(moved)));
+            }
+            let Some(i) = (0..types.len()).find(|i| choice[*i] + 1 < types[*i].options().len()) else {
+                return best;
+            };
+            choice[i] += 1;
+            choice[..i].fill(0);
+        }
+    }
+
+    /// The item types and sizes of both compartments after `moves`.
+    fn compartments_after(rucksack: &Rucksack, moves: &[Move]) -> (ItemSet, ItemSet, usize, usize) {
+        let (first, second) = rucksack.compartments();
+        let (mut first_items, mut second_items) = (String::from(first), String::from(second));
+        for m in moves.iter().filter(|m| m.line == rucksack.line) {
+            let (from, to) = match m.from {
+                Compartment::First => (&mut first_items, &mut second_items),
+                Compartment::Second => (&mut second_items, &mut first_items),
+            };
+            for _ in 0..m.count {
+                let position = from.find(m.item).expect("move of an item that is not in the compartment");
+                from.remove(position);
+                to.push(m.item);
+            }
+        }
+        (first_items.parse().unwrap(), second_items.parse().unwrap(), first_items.len(), second_items.len())
+    }
+
+    fn check(rucksack: &Rucksack, capacity: usize, moves: &[Move]) {
+        let (first, second, first_len, second_len) = compartments_after(rucksack, moves);
+        assert!(first.intersection(second).is_empty(), "{:?} {:?}", rucksack, moves);
+        assert!(first_len <= capacity && second_len <= capacity, "{:?} {:?}", rucksack, moves);
+    }
+
+    #[test]
+    fn shared_items_move_to_one_compartment() {
+        let moves = reorganise_rucksack(&rucksack("abcb"), 10).unwrap();
+        assert_eq!(moves, vec![Move { line: 1, item: 'b', count: 1, from: Compartment::Second }]);
+        assert_eq!(moves[0].to_string(), "line 1: move 1 x b from the second to the first compartment");
+        assert_eq!(reorganise_rucksack(&rucksack("abcd"), 2), Some(vec![]));
+    }
+
+    #[test]
+    fn full_compartments_need_a_swap() {
+        let example = rucksack("vJrwpWtwJgWrhcsFMMfFFhFp");
+        let moves = reorganise_rucksack(&example, 12).unwrap();
+        assert_eq!(moves.iter().map(|m| m.count).sum::<usize>(), 2);
+        check(&example, 12, &moves);
+        assert_eq!(reorganise_rucksack(&example, 13).unwrap().len(), 1);
+        // every type of "aaab" has to end up in one compartment of two items
+        assert_eq!(reorganise_rucksack(&rucksack("aaab"), 2), None);
+    }
+
+    #[test]
+    fn reorganise_example() {
+        let rucksacks = parse_rucksacks(include_str!("../example.txt")).unwrap();
+        let plan = reorganise(&rucksacks, 100);
+        assert_eq!(plan.moves.len(), 6);
+        // without a capacity every shared type leaves the compartment that has fewer of it
+        let fewer: usize = rucksacks.iter().flat_map(item_types).map(|t| t.first.min(t.second)).sum();
+        assert_eq!(plan.item_count(), fewer);
+        assert!(plan.unfixable.is_empty());
+        assert_eq!(plan.priority_sum, 0);
+        let plan = reorganise(&rucksacks, 1);
+        assert_eq!(plan.unfixable, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(plan.priority_sum, 157);
+    }
+
+    #[test]
+    fn planner_matches_brute_force() {
+        let mut rng = common::rng::Rng::new(7);
+        for _ in 0..300 {
+            let half = rng.range(1, 5) as usize;
+            let items: String = (0..2 * half).map(|_| rng.pick(&['a', 'b', 'c', 'd', 'e'])).collect();
+            let rucksack = rucksack(&items);
+            for capacity in half - 1..=2 * half {
+                let moves = reorganise_rucksack(&rucksack, capacity);
+                let moved = moves.as_ref().map(|moves| moves.iter().map(|m| m.count).sum());
+                assert_eq!(moved, brute_force(&rucksack, capacity), "{} {}", items, capacity);
+                if let Some(moves) = moves {
+                    check(&rucksack, capacity, &moves);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt;
+
+use common::diagnostic::Location;
+
+/// Location of the puzzle input in the source tree, for benchmarks and tests.
+pub const PUZZLE_INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/puzzle_input.txt");
+/// The puzzle input embedded at build time, binaries fall back to it wherever they are installed.
+pub const PUZZLE_INPUT: &str = include_str!("../puzzle_input.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingSeparator(char, Location),
+    InvalidSectionId(Location),
+}
+
+impl ParseError {
+    fn at_line(self, line: usize) -> Self {
+        match self {
+            ParseError::MissingSeparator(c, location) => ParseError::MissingSeparator(c, location.at_line(line)),
+            ParseError::InvalidSectionId(location) => ParseError::InvalidSectionId(location.at_line(line)),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingSeparator(c, location) => write!(f, "expected '{}' at {}", c, location),
+            ParseError::InvalidSectionId(location) => write!(f, "section id must be a number at {}", location),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn split_at_separator<'a>(input: &'a str, part: &'a str, separator: char) -> Result<(&'a str, &'a str), ParseError> {
+    part.split_once(separator)
+        .ok_or(ParseError::MissingSeparator(separator, Location::of(1, input, &part[part.len()..])))
+}
+
+fn parse_section_id(input: &str, part: &str) -> Result<i32, ParseError> {
+    part.parse::<i32>().map_err(|_| ParseError::InvalidSectionId(Location::of(1, input, part)))
+}
+
+#[derive(Debug)]
+pub struct ElvesPair {
+    a_l: i32,
+    a_u: i32,
+
+    b_l: i32,
+    b_u: i32,
+}
+
+impl ElvesPair {
+    /// Parses a single line like `2-4,6-8`. Errors are reported for line 1.
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (first, second) = split_at_separator(input, input, ',')?;
+        let ((a_l, a_u), (b_l, b_u)) = (split_at_separator(input, first, '-')?, split_at_separator(input, second, '-')?);
+
+        Ok(Self {
+            a_l: parse_section_id(input, a_l)?,
+            a_u: parse_section_id(input, a_u)?,
+            b_l: parse_section_id(input, b_l)?,
+            b_u: parse_section_id(input, b_u)?,
+        })
+    }
+
+    pub fn check_for_any_overlap(&self) -> bool {
+        self.a_l <= self.b_u && self.b_l <= self.a_u
+    }
+}
+
+
+pub fn parse_elves_pairs(contents: &str) -> Result<Vec<ElvesPair>, ParseError> {
+    contents.lines()
+        .enumerate()
+        .map(|(i, l)| ElvesPair::new(l).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+pub fn count_overlapping_pairs(contents: &str) -> Result<usize, ParseError> {
+    let elves = parse_elves_pairs(contents)?;
+    Ok(elves.iter().filter(|ep| ep.check_for_any_overlap()).count())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn elves_pair_no_overlap() {
+        let ep = ElvesPair::new("1-1,2-2").unwrap();
+        assert!(!ep.check_for_any_overlap());
+    }
+
+    #[test]
+    fn elves_pair_full_overlap_1() {
+        let ep = ElvesPair::new("1-1,1-1").unwrap();
+        assert!(ep.check_for_any_overlap());
+    }
+
+    #[test]
+    fn elves_pair_full_overlap_2() {
+        let ep = ElvesPair::new("4-110,4-110").unwrap();
+        assert!(ep.check_for_any_overlap());
+    }
+
+    #[test]
+    fn elves_pair_partial_overlap_1() {
+        let ep = ElvesPair::new("1-5,4-6").unwrap();
+        assert!(ep.check_for_any_overlap());
+    }
+
+    #[test]
+    fn elves_pair_completely_enclosed_overlap() {
+        let ep = ElvesPair::new("1-5,3-4").unwrap();
+        assert!(ep.check_for_any_overlap());
+    }
+
+    #[test]
+    fn example_overlap_1() {
+        let ep = ElvesPair::new("5-7,7-9").unwrap();
+        assert!(ep.check_for_any_overlap());
+    }
+
+    #[test]
+    fn example_overlap_2() {
+        let ep = ElvesPair::new("2-8,3-7").unwrap();
+        assert!(ep.check_for_any_overlap());
+    }
+
+    #[test]
+    fn example_overlap_3() {
+        let ep = ElvesPair::new("6-6,4-6").unwrap();
+        assert!(ep.check_for_any_overlap());
+    }
+
+    #[test]
+    fn example_overlap_4() {
+        let ep = ElvesPair::new("2-6,4-8").unwrap();
+        assert!(ep.check_for_any_overlap());
+    }
+
+    #[test]
+    fn example_overlap_5() {
+        let ep = ElvesPair::new("2-4,6-8").unwrap();
+        assert!(!ep.check_for_any_overlap());
+    }
+
+    #[test]
+    fn example_overlap_6() {
+        let ep = ElvesPair::new("2-3,4-5").unwrap();
+        assert!(!ep.check_for_any_overlap());
+    }
+
+    #[test]
+    fn missing_separator_is_an_error() {
+        let error = parse_elves_pairs("1-2,3-4\n1-2 3-4").unwrap_err();
+        assert_eq!(error, ParseError::MissingSeparator(',', Location::new(2, 8, "1-2 3-4")));
+
+        let error = parse_elves_pairs("1-2,34").unwrap_err();
+        assert_eq!(error, ParseError::MissingSeparator('-', Location::new(1, 7, "1-2,34")));
+    }
+
+    #[test]
+    fn invalid_section_id_is_an_error() {
+        let error = parse_elves_pairs("1-2,3-x").unwrap_err();
+        assert_eq!(error, ParseError::InvalidSectionId(Location::new(1, 7, "1-2,3-x")));
+    }
+}
@@ -0,0 +1,372 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+use common::diagnostic::Location;
+
+/// Location of the puzzle input in the source tree, for benchmarks and tests.
+pub const PUZZLE_INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/puzzle_input.txt");
+/// The puzzle input embedded at build time, binaries fall back to it wherever they are installed.
+pub const PUZZLE_INPUT: &str = include_str!("../puzzle_input.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingMoves(Location),
+    InvalidMove(Location),
+    UnknownStack(Location),
+    EmptyStack(Location),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingMoves(location) => write!(f, "expected an empty line between crates and moves at {}", location),
+            ParseError::InvalidMove(location) => write!(f, "expected a move like 'move 1 from 2 to 3' at {}", location),
+            ParseError::UnknownStack(location) => write!(f, "stack does not exist at {}", location),
+            ParseError::EmptyStack(location) => write!(f, "can not move a crate from an empty stack at {}", location),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Clone)]
+struct Move {
+    src: usize,
+    dest: usize,
+    count: i32,
+    location: Location,
+}
+
+#[derive(Clone)]
+pub struct Stacks {
+    crates: HashMap<i32, VecDeque<char>>,
+    moves: Vec<Move>,
+}
+
+fn line_to_stack(stacks: &mut HashMap<i32, VecDeque<char>>, line: &str) {
+    if !line.contains("[") {
+        return;
+    }
+    let chars = line.replace("    ", "[_] ").replace(['[', ' ', ']'], "");
+    for (pos, c) in chars.chars().enumerate() {
+        stacks.entry(pos as i32).or_insert_with(|| VecDeque::from([]));
+        if c == '_' {
+            continue;
+        }
+        stacks.entry(pos as i32).and_modify(|s| s.push_front(c));
+    }
+}
+
+fn parse_move_number(line_number: usize, line: &str, token: Option<&str>) -> Result<i32, ParseError> {
+    let token = token.unwrap_or(&line[line.len()..]);
+    token.parse::<i32>().map_err(|_| ParseError::InvalidMove(Location::of(line_number, line, token)))
+}
+
+fn line_to_move(line_number: usize, line: &str) -> Result<Move, ParseError> {
+    let mut tokens = line.split(' ');
+    let mut numbers_as_str = [0; 3];
+    for (i, keyword) in ["move", "from", "to"].iter().enumerate() {
+        let token = tokens.next().unwrap_or(&line[line.len()..]);
+        if token != *keyword {
+            return Err(ParseError::InvalidMove(Location::of(line_number, line, token)));
+        }
+        numbers_as_str[i] = parse_move_number(line_number, line, tokens.next())?;
+    }
+    if let Some(token) = tokens.next() {
+        return Err(ParseError::InvalidMove(Location::of(line_number, line, token)));
+    }
+    let location = Location::new(line_number, 1, line);
+    Ok(Move { src: numbers_as_str[1] as usize, dest: numbers_as_str[2] as usize, count: numbers_as_str[0], location })
+}
+
+/// `token_idx` is the position of the stack number in the move line, used to point at it on errors.
+fn check_stack_exists(stacks: &HashMap<i32, VecDeque<char>>, m: &Move, stack_number: usize, token_idx: usize) -> Result<(), ParseError> {
+    if stacks.contains_key(&(stack_number as i32 - 1)) {
+        return Ok(());
+    }
+    let line = &m.location.snippet;
+    let token = line.split(' ').nth(token_idx).unwrap_or(line);
+    Err(ParseError::UnknownStack(Location::of(m.location.line, line, token)))
+}
+
+impl Stacks {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let mut stacks = HashMap::new();
+        let (crates_input, moves_input) = input.split_once("\n\n").ok_or_else(|| {
+            let last_line = input.lines().last().unwrap_or("");
+            let location = Location::new(input.lines().count().max(1), last_line.chars().count() + 1, last_line);
+            ParseError::MissingMoves(location)
+        })?;
+        let lines = crates_input.lines().collect::<Vec<_>>();
+
+        lines.iter().for_each(|l|
+            line_to_stack(&mut stacks, l)
+        );
+
+        // moves start after the crate drawing and the empty separator line
+        let first_move_line = crates_input.split('\n').count() + 2;
+        let lines = moves_input.lines().collect::<Vec<_>>();
+        let mut moves = vec![];
+        for (i, l) in lines.iter().enumerate() {
+            let m = line_to_move(first_move_line + i, l)?;
+            check_stack_exists(&stacks, &m, m.src, 3)?;
+            check_stack_exists(&stacks, &m, m.dest, 5)?;
+            moves.push(m);
+        }
+        Ok(Self { crates: stacks, moves })
+    }
+
+    pub fn apply_moves(&mut self) -> Result<(), ParseError> {
+        for m in &self.moves {
+            for _ in 0..m.count {
+                let c = self.crates.get_mut(&(m.src as i32 - 1)).and_then(|stack| stack.pop_back())
+                    .ok_or(ParseError::EmptyStack(m.location.clone()))?;
+                self.crates.entry(m.dest as i32 - 1).and_modify(|stack| stack.push_back(c));
+            }
+        }
+        Ok(())
+    }
+
+    /// The top crate of every stack from left to right, stacks that ended up empty are skipped.
+    pub fn get_top_crates(&self) -> String {
+        let mut a_str = String::from("");
+
+        for i in 0..self.crates.len() {
+            if let Some(top) = self.crates.get(&(i as i32)).and_then(|stack| stack.back()) {
+                a_str.push(*top);
+            }
+        }
+
+        a_str
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn empty_stacks_have_no_top_crate() {
+        let mut stacks = Stacks::new("    [A]\n 1   2 \n\nmove 1 from 2 to 1").unwrap();
+        stacks.apply_moves().unwrap();
+        assert_eq!(stacks.get_top_crates(), "A");
+    }
+
+    #[test]
+    fn empty_string_leads_to_empty_stacks() {
+        let contents = "\n\n";
+
+        let stack = Stacks::new(contents).unwrap();
+
+        assert_eq!(stack.crates.len(), 0)
+    }
+
+    #[test]
+    fn parse_one_stack_with_one_crate() {
+        let contents = "[A]\n\n";
+
+        let stack = Stacks::new(contents).unwrap();
+
+        assert_eq!(stack.crates.len(), 1);
+        assert_eq!(stack.crates.get(&0).unwrap().len(), 1);
+        assert_eq!(stack.crates.get(&0).unwrap()[0], 'A');
+    }
+
+    #[test]
+    fn parse_two_stack_with_one_crate_each() {
+        let contents = "[A] [B]\n\n";
+
+        let stack = Stacks::new(contents).unwrap();
+
+        assert_eq!(stack.crates.len(), 2);
+        assert_eq!(stack.crates.get(&0).unwrap().len(), 1);
+        assert_eq!(stack.crates.get(&0).unwrap()[0], 'A');
+        assert_eq!(stack.crates.get(&1).unwrap().len(), 1);
+        assert_eq!(stack.crates.get(&1).unwrap()[0], 'B');
+    }
+
+    #[test]
+    fn parse_two_stack_with_one_crate_in_second_stack() {
+        let contents = "    [B]\n\n";
+
+        let stack = Stacks::new(contents).unwrap();
+
+        assert_eq!(stack.crates.len(), 2);
+        assert_eq!(stack.crates.get(&0).unwrap().len(), 0);
+        assert_eq!(stack.crates.get(&1).unwrap().len(), 1);
+        assert_eq!(stack.crates.get(&1).unwrap()[0], 'B');
+    }
+
+    #[test]
+    fn parse_two_stack_with_two_crates_in_first_stack() {
+        let contents = "[B]    \n[A]    \n\n";
+
+        let stack = Stacks::new(contents).unwrap();
+
+        assert_eq!(stack.crates.len(), 2);
+        assert_eq!(stack.crates.get(&0).unwrap().len(), 2);
+        assert_eq!(stack.crates.get(&1).unwrap().len(), 0);
+        assert_eq!(stack.crates.get(&0).unwrap()[1], 'B');
+        assert_eq!(stack.crates.get(&0).unwrap()[0], 'A');
+    }
+
+    #[test]
+    fn parse_two_stack_with_two_crates_in_both_stacks() {
+        let contents = "[A] [B]\n[C] [D]\n\n";
+
+        let stack = Stacks::new(contents).unwrap();
+
+        assert_eq!(stack.crates.len(), 2);
+        assert_eq!(stack.crates.get(&0).unwrap().len(), 2);
+        assert_eq!(stack.crates.get(&1).unwrap().len(), 2);
+
+        assert_eq!(stack.crates.get(&0).unwrap()[1], 'A');
+        assert_eq!(stack.crates.get(&1).unwrap()[1], 'B');
+        assert_eq!(stack.crates.get(&0).unwrap()[0], 'C');
+        assert_eq!(stack.crates.get(&1).unwrap()[0], 'D');
+    }
+
+    #[test]
+    fn parse_two_stack_with_two_crates_in_second_stack() {
+        let contents = "    [B]\n    [A]\n\n";
+
+        let stack = Stacks::new(contents).unwrap();
+
+        assert_eq!(stack.crates.len(), 2);
+        assert_eq!(stack.crates.get(&0).unwrap().len(), 0);
+        assert_eq!(stack.crates.get(&1).unwrap().len(), 2);
+        assert_eq!(stack.crates.get(&1).unwrap()[1], 'B');
+        assert_eq!(stack.crates.get(&1).unwrap()[0], 'A');
+    }
+
+    #[test]
+    fn apply_simple_move() {
+        let contents = "[A] [B]\n\nmove 1 from 1 to 2";
+
+        let mut stack = Stacks::new(contents).unwrap();
+
+        stack.apply_moves().unwrap();
+
+        assert_eq!(stack.crates.len(), 2);
+        assert_eq!(stack.crates.get(&0).unwrap().len(), 0);
+        assert_eq!(stack.crates.get(&1).unwrap().len(), 2);
+
+        assert_eq!(stack.crates.get(&1).unwrap()[0], 'B');
+        assert_eq!(stack.crates.get(&1).unwrap()[1], 'A');
+    }
+
+    #[test]
+    fn apply_move_of_two_entries() {
+        let contents = "[A]\n[A] [B]\n\nmove 2 from 1 to 2";
+
+        let mut stack = Stacks::new(contents).unwrap();
+
+        stack.apply_moves().unwrap();
+
+        assert_eq!(stack.crates.len(), 2);
+        assert_eq!(stack.crates.get(&0).unwrap().len(), 0);
+        assert_eq!(stack.crates.get(&1).unwrap().len(), 3);
+
+        assert_eq!(stack.crates.get(&1).unwrap()[0], 'B');
+        assert_eq!(stack.crates.get(&1).unwrap()[1], 'A');
+        assert_eq!(stack.crates.get(&1).unwrap()[2], 'A');
+    }
+
+    #[test]
+    fn parse_crates_from_example_input() {
+        let contents = include_str!("../example.txt");
+
+        let mut stack = Stacks::new(contents).unwrap();
+        assert_eq!(stack.crates.len(), 3);
+        assert_eq!(stack.crates.get(&0).unwrap().len(), 2);
+        assert_eq!(stack.crates.get(&0).unwrap()[0], 'Z');
+        assert_eq!(stack.crates.get(&0).unwrap()[1], 'N');
+
+        assert_eq!(stack.crates.get(&1).unwrap().len(), 3);
+        assert_eq!(stack.crates.get(&1).unwrap()[0], 'M');
+        assert_eq!(stack.crates.get(&1).unwrap()[1], 'C');
+        assert_eq!(stack.crates.get(&1).unwrap()[2], 'D');
+
+        assert_eq!(stack.crates.get(&2).unwrap().len(), 1);
+        assert_eq!(stack.crates.get(&2).unwrap()[0], 'P');
+
+
+        assert_eq!(stack.moves.len(), 4);
+        assert_eq!(stack.moves[0].src, 2);
+        assert_eq!(stack.moves[0].dest, 1);
+        assert_eq!(stack.moves[0].count, 1);
+
+        assert_eq!(stack.moves[1].src, 1);
+        assert_eq!(stack.moves[1].dest, 3);
+        assert_eq!(stack.moves[1].count, 3);
+
+        assert_eq!(stack.moves[2].src, 2);
+        assert_eq!(stack.moves[2].dest, 1);
+        assert_eq!(stack.moves[2].count, 2);
+
+        assert_eq!(stack.moves[3].src, 1);
+        assert_eq!(stack.moves[3].dest, 2);
+        assert_eq!(stack.moves[3].count, 1);
+
+        stack.apply_moves().unwrap();
+
+        assert_eq!(stack.crates.len(), 3);
+        assert_eq!(stack.crates.get(&0).unwrap().len(), 1);
+        assert_eq!(stack.crates.get(&1).unwrap().len(), 1);
+        assert_eq!(stack.crates.get(&2).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn parse_crates_from_puzzle_input() {
+        let contents = include_str!("../puzzle_input.txt");
+
+        let stack = Stacks::new(contents).unwrap();
+        assert_eq!(stack.crates.len(), 9);
+    }
+
+    #[test]
+    fn parse_crates_long_line() {
+        let contents = "            [J]             [B] [W]\n\n";
+        let stack = Stacks::new(contents).unwrap();
+        assert_eq!(stack.crates.len(), 9);
+    }
+
+    #[test]
+    fn missing_moves_separator_is_an_error() {
+        let error = Stacks::new("[A] [B]\n 1   2 ").err().unwrap();
+        assert_eq!(error, ParseError::MissingMoves(Location::new(2, 8, " 1   2 ")));
+    }
+
+    #[test]
+    fn invalid_move_is_an_error() {
+        let contents = "[A] [B]\n 1   2 \n\nmove 1 from 1 to 2\nmove 1 from x to 2";
+        let error = Stacks::new(contents).err().unwrap();
+        assert_eq!(error, ParseError::InvalidMove(Location::new(5, 13, "move 1 from x to 2")));
+    }
+
+    #[test]
+    fn incomplete_move_is_an_error() {
+        let contents = "[A] [B]\n\nmove 1 from 1";
+        let error = Stacks::new(contents).err().unwrap();
+        assert_eq!(error, ParseError::InvalidMove(Location::new(3, 14, "move 1 from 1")));
+    }
+
+    #[test]
+    fn move_to_unknown_stack_is_an_error() {
+        let contents = "[A] [B]\n\nmove 1 from 1 to 3";
+        let error = Stacks::new(contents).err().unwrap();
+        assert_eq!(error, ParseError::UnknownStack(Location::new(3, 18, "move 1 from 1 to 3")));
+
+        let contents = "[A] [B]\n\nmove 3 from 3 to 1";
+        let error = Stacks::new(contents).err().unwrap();
+        assert_eq!(error, ParseError::UnknownStack(Location::new(3, 13, "move 3 from 3 to 1")));
+    }
+
+    #[test]
+    fn move_from_empty_stack_is_an_error() {
+        let contents = "[A] [B]\n\nmove 2 from 1 to 2";
+        let mut stack = Stacks::new(contents).unwrap();
+        let error = stack.apply_moves().unwrap_err();
+        assert_eq!(error, ParseError::EmptyStack(Location::new(3, 1, "move 2 from 1 to 2")));
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use ringbuffer::{AllocRingBuffer, RingBuffer};
+
+/// Location of the puzzle input in the source tree, for benchmarks and tests.
+pub const PUZZLE_INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/puzzle_input.txt");
+/// The puzzle input embedded at build time, binaries fall back to it wherever they are installed.
+pub const PUZZLE_INPUT: &str = include_str!("../puzzle_input.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// No 4 consecutive characters are all different in the stream of the given length.
+    NoMarker(usize),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::NoMarker(length) => {
+                write!(f, "no 4 different characters in a row in the stream of {} characters", length)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Position just after the first marker, the line ending of the stream is not part of it.
+pub fn find_start_signal_position(content: &str) -> Result<usize, ParseError> {
+    let content = content.trim_end();
+    let mut buffer = AllocRingBuffer::new(4);
+
+    for (pos, c) in content.chars().enumerate() {
+        buffer.push(c);
+        if !buffer.is_full() {
+            continue;
+        }
+
+        let set: HashSet<char> = HashSet::from_iter(buffer.iter().cloned());
+        if set.len() == 4 {
+            return Ok(pos + 1);
+        }
+    }
+
+    Err(ParseError::NoMarker(content.chars().count()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn first_example_returns_7() {
+        let content = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+        let result = find_start_signal_position(content).unwrap();
+
+        assert_eq!(result, 7);
+    }
+
+    #[test]
+    fn second_example_returns_5() {
+        let content = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+        let result = find_start_signal_position(content).unwrap();
+
+        assert_eq!(result, 5);
+    }
+
+    #[test]
+    fn third_example_returns_6() {
+        let content = "nppdvjthqldpwncqszvftbrmjlhg";
+        let result = find_start_signal_position(content).unwrap();
+
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn fourth_example_returns_10() {
+        let content = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+        let result = find_start_signal_position(content).unwrap();
+
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn fifth_example_returns_11() {
+        let content = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+        let result = find_start_signal_position(content).unwrap();
+
+        assert_eq!(result, 11);
+    }
+
+    #[test]
+    fn stream_without_marker_is_an_error() {
+        let result = find_start_signal_position("abcabc\n");
+
+        assert_eq!(result, Err(ParseError::NoMarker(6)));
+        assert_eq!(result.unwrap_err().to_string(), "no 4 different characters in a row in the stream of 6 characters");
+    }
+}
@@ -0,0 +1,369 @@
+use std::fmt;
+
+use common::diagnostic::Location;
+
+/// Location of the puzzle input in the source tree, for benchmarks and tests.
+pub const PUZZLE_INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/puzzle_input.txt");
+/// The puzzle input embedded at build time, binaries fall back to it wherever they are installed.
+pub const PUZZLE_INPUT: &str = include_str!("../puzzle_input.txt");
+
+pub struct Directory {
+    pub name: String,
+    subdirs: Vec<Directory>,
+    direct_files_size: u64,
+    parent: Option<*mut Directory>,
+}
+
+impl Directory {
+    fn add_file(&mut self, size: u64) {
+        self.direct_files_size += size;
+    }
+
+    fn add_directory(&mut self, name: String, parent: *mut Directory) {
+        let new_dir = Directory { name, subdirs: vec![], direct_files_size: 0, parent: Some(parent) };
+        self.subdirs.push(new_dir);
+    }
+
+    pub fn get_directory_size(&self) -> u64 {
+        self.direct_files_size + self.subdirs.iter().map(|d| d.get_directory_size()).sum::<u64>()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    UnknownCommand(Location),
+    MissingDirectoryName(Location),
+    CdAboveRoot(Location),
+    InvalidFileEntry(Location),
+    InvalidFileSize(Location),
+    OutputBeforeCommand(Location),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnknownCommand(location) => write!(f, "command must be 'cd' or 'ls' at {}", location),
+            ParseError::MissingDirectoryName(location) => write!(f, "expected a directory name after 'cd' at {}", location),
+            ParseError::CdAboveRoot(location) => write!(f, "can not leave the root directory at {}", location),
+            ParseError::InvalidFileEntry(location) => write!(f, "expected 'dir <name>' or '<size> <name>' at {}", location),
+            ParseError::InvalidFileSize(location) => write!(f, "file size must be a number at {}", location),
+            ParseError::OutputBeforeCommand(location) => write!(f, "expected a '$' command before any output at {}", location),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+struct Command {
+    cmd: String,
+    output: Vec<String>,
+    /// line number of the command, the output follows on the next lines
+    line: usize,
+}
+
+impl Command {
+    fn location_in_command(&self, part: &str) -> Location {
+        // the command is stored without its "$ " prompt
+        let column = Location::of(self.line, &self.cmd, part).column + 2;
+        Location::new(self.line, column, &format!("$ {}", self.cmd))
+    }
+}
+
+
+fn content_to_commands(content: &str) -> Result<Vec<Command>, ParseError>
+{
+    let mut result = vec![];
+
+    let lines = content.lines().collect::<Vec<_>>();
+    let mut current_command: Option<Command> = None;
+    for (i, line) in lines.into_iter().enumerate() {
+        if line.starts_with("$")
+        {
+            // store the old current command
+            if let Some(command) = current_command {
+                result.push(command);
+            }
+
+            let cmd_str = line.get(2..).unwrap_or("");
+            current_command = Some(Command { cmd: String::from(cmd_str), output: vec![], line: i + 1 });
+        } else {
+            if current_command.is_none() {
+                return Err(ParseError::OutputBeforeCommand(Location::new(i + 1, 1, line)));
+            }
+
+            // can only unwrap once
+            let old_command = current_command.unwrap();
+            let old_cmd = old_command.cmd.clone();
+            let mut old_output = old_command.output.clone();
+            old_output.push(line.to_string());
+            current_command = Some(Command { cmd: old_cmd, output: old_output, line: old_command.line });
+        }
+    }
+    if let Some(command) = current_command {
+        // push last command if existing
+        result.push(command);
+    }
+
+    Ok(result)
+}
+
+fn apply_commands(root_dir: &mut Directory, commands: Vec<Command>) -> Result<(), ParseError>
+{
+    unsafe {
+        let mut current_dir: *mut Directory = root_dir;
+        for c in commands {
+            if c.cmd == "ls" {
+                for (i, result) in c.output.iter().enumerate() {
+                    if result.starts_with("dir") {
+                        // ignore
+                        continue;
+                    }
+                    let line = c.line + i + 1;
+                    let (size, _) = result.split_once(" ")
+                        .ok_or_else(|| ParseError::InvalidFileEntry(Location::new(line, 1, result)))?;
+                    let size = size.parse::<u64>()
+                        .map_err(|_| ParseError::InvalidFileSize(Location::new(line, 1, result)))?;
+                    current_dir.as_mut().unwrap().add_file(size);
+                }
+            } else if c.cmd.starts_with("cd") {
+                let (_, dir_name) = c.cmd.split_once(" ")
+                    .ok_or_else(|| ParseError::MissingDirectoryName(c.location_in_command(&c.cmd[c.cmd.len()..])))?;
+                if dir_name == ".." {
+                    current_dir = current_dir.as_mut().unwrap().parent
+                        .ok_or_else(|| ParseError::CdAboveRoot(c.location_in_command(dir_name)))?;
+                    continue;
+                } else if dir_name == "/" {
+                    current_dir = root_dir;
+                    continue;
+                }
+                current_dir.as_mut().unwrap().add_directory(String::from(dir_name), current_dir);
+                current_dir = current_dir.as_mut().unwrap().subdirs.last_mut().unwrap();
+            } else {
+                return Err(ParseError::UnknownCommand(c.location_in_command(&c.cmd)));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn get_directories(content: &str) -> Result<Directory, ParseError> {
+    let mut root_dir = Directory { name: String::from("/"), subdirs: vec![], direct_files_size: 0, parent: None };
+    let commands = content_to_commands(content)?;
+    apply_commands(&mut root_dir, commands)?;
+    Ok(root_dir)
+}
+
+fn get_filesize_if_larger_than(dir: &Directory) -> u64
+{
+    let dir_size = dir.get_directory_size();
+    if dir_size < 100000 {
+        return dir_size;
+    }
+    0
+}
+
+pub fn add_up_file_size_if_larger_than(dir: &Directory) -> u64
+{
+    let mut size = 0;
+    size += get_filesize_if_larger_than(dir);
+
+    for d in &dir.subdirs {
+        size += add_up_file_size_if_larger_than(d);
+    }
+
+    size
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example_1_full() {
+        let contents = include_str!("../example.txt");
+        let dirs = get_directories(contents).unwrap();
+        let returned_size = add_up_file_size_if_larger_than(&dirs);
+        assert_eq!(returned_size, 95437);
+    }
+
+    #[test]
+    fn example_1_parse_commands() {
+        let contents = include_str!("../example.txt");
+        let commands = content_to_commands(contents).unwrap();
+
+        assert_eq!(commands.len(), 10);
+        assert_eq!(commands[0].cmd, "cd /");
+        assert_eq!(commands[1].cmd, "ls");
+        let expected_output = vec![
+            String::from("dir a"),
+            String::from("14848514 b.txt"),
+            String::from("8504156 c.dat"),
+            String::from("dir d"),
+        ];
+        assert_eq!(commands[1].output, expected_output);
+        assert_eq!(commands[2].cmd, "cd a");
+        assert_eq!(commands[3].cmd, "ls");
+        assert_eq!(commands[4].cmd, "cd e");
+        assert_eq!(commands[5].cmd, "ls");
+        assert_eq!(commands[6].cmd, "cd ..");
+        assert_eq!(commands[7].cmd, "cd ..");
+        assert_eq!(commands[8].cmd, "cd d");
+        assert_eq!(commands[9].cmd, "ls");
+    }
+
+    #[test]
+    fn empty_input_results_in_empty_root_dir() {
+        let contents = "";
+        let root_dir = get_directories(contents).unwrap();
+        assert_eq!(root_dir.name, "/");
+        assert_eq!(root_dir.subdirs.len(), 0);
+        assert_eq!(root_dir.direct_files_size, 0);
+    }
+
+    #[test]
+    fn parse_ls_without_result_command() {
+        let contents = "$ ls";
+        let commands = content_to_commands(contents).unwrap();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].cmd, "ls");
+        let expected_output: Vec<String> = vec![];
+        assert_eq!(commands[0].output, expected_output);
+    }
+
+    #[test]
+    fn parse_ls_with_one_line_result() {
+        let contents = "$ ls\n123 a.txt";
+        let commands = content_to_commands(contents).unwrap();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].cmd, "ls");
+        let expected_output: Vec<String> = vec![String::from("123 a.txt")];
+        assert_eq!(commands[0].output, expected_output);
+    }
+
+    #[test]
+    fn parse_ls_with_two_line_result() {
+        let contents = "$ ls\n123 a.txt\n456 b.txt";
+        let commands = content_to_commands(contents).unwrap();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].cmd, "ls");
+        let expected_output: Vec<String> = vec![String::from("123 a.txt"), String::from("456 b.txt")];
+        assert_eq!(commands[0].output, expected_output);
+    }
+
+    #[test]
+    fn parse_cd_and_ls_command() {
+        let contents = "$ cd /\n$ ls";
+        let commands = content_to_commands(contents).unwrap();
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].cmd, "cd /");
+        assert_eq!(commands[1].cmd, "ls");
+    }
+
+
+    #[test]
+    fn parse_root_dir_with_one_file() {
+        let contents = "$ cd /\n$ ls\n123 a.txt";
+        let root_dir = get_directories(contents).unwrap();
+        assert_eq!(root_dir.name, "/");
+        assert_eq!(root_dir.subdirs.len(), 0);
+        assert_eq!(root_dir.direct_files_size, 123);
+    }
+
+    #[test]
+    fn parse_root_dir_with_two_files() {
+        let contents = "$ cd /\n$ ls\n123 a.txt\n100 b.txt";
+        let root_dir = get_directories(contents).unwrap();
+        assert_eq!(root_dir.name, "/");
+        assert_eq!(root_dir.subdirs.len(), 0);
+        assert_eq!(root_dir.direct_files_size, 223);
+    }
+
+    #[test]
+    fn parse_dir_with_two_files_in_sub_dir() {
+        let contents = "$ cd /\n$ cd a\n$ ls\n123 a.txt\n100 b.txt";
+        let root_dir = get_directories(contents).unwrap();
+        assert_eq!(root_dir.name, "/");
+        assert_eq!(root_dir.subdirs.len(), 1);
+        assert_eq!(root_dir.direct_files_size, 0);
+        assert_eq!(root_dir.subdirs[0].direct_files_size, 223);
+    }
+
+    #[test]
+    fn parse_dir_with_one_file_in_root_dir_and_one_files_in_sub_dir() {
+        let contents = "$ cd /\n$ cd a\n$ ls\n100 b.txt\n$ cd /\n$ ls\n123 a.txt";
+        let root_dir = get_directories(contents).unwrap();
+        assert_eq!(root_dir.name, "/");
+        assert_eq!(root_dir.subdirs.len(), 1);
+        assert_eq!(root_dir.direct_files_size, 123);
+        assert_eq!(root_dir.subdirs[0].direct_files_size, 100);
+    }
+
+    #[test]
+    fn parse_dir_with_going_up_in_folders() {
+        let contents = "$ cd /\n$ cd a\n$ cd b\n$ ls\n100 a.txt\n$ cd ..\n$ ls\n200 b.txt";
+        let root_dir = get_directories(contents).unwrap();
+        assert_eq!(root_dir.name, "/");
+        assert_eq!(root_dir.subdirs.len(), 1);
+        assert_eq!(root_dir.subdirs[0].direct_files_size, 200);
+        assert_eq!(root_dir.subdirs[0].subdirs.len(), 1);
+        assert_eq!(root_dir.subdirs[0].subdirs[0].subdirs.len(), 0);
+        assert_eq!(root_dir.subdirs[0].subdirs[0].direct_files_size, 100);
+    }
+
+    #[test]
+    pub fn get_directory_size() {
+        let contents = "$ cd /\n$ cd a\n$ cd b\n$ ls\n100 a.txt\n$ cd ..\n$ ls\n200 b.txt";
+        let root_dir = get_directories(contents).unwrap();
+        let result_size = root_dir.get_directory_size();
+        assert_eq!(result_size, 300);
+    }
+
+    #[test]
+    fn unknown_command_is_an_error() {
+        let contents = "$ cd /\n$ rm -rf a";
+        let error = get_directories(contents).err().unwrap();
+        assert_eq!(error, ParseError::UnknownCommand(Location::new(2, 3, "$ rm -rf a")));
+    }
+
+    #[test]
+    fn cd_without_directory_is_an_error() {
+        let contents = "$ cd";
+        let error = get_directories(contents).err().unwrap();
+        assert_eq!(error, ParseError::MissingDirectoryName(Location::new(1, 5, "$ cd")));
+    }
+
+    #[test]
+    fn cd_above_root_is_an_error() {
+        let contents = "$ cd /\n$ cd a\n$ cd ..\n$ cd ..";
+        let error = get_directories(contents).err().unwrap();
+        assert_eq!(error, ParseError::CdAboveRoot(Location::new(4, 6, "$ cd ..")));
+    }
+
+    #[test]
+    fn invalid_ls_output_is_an_error() {
+        let contents = "$ cd /\n$ ls\n123 a.txt\n456";
+        let error = get_directories(contents).err().unwrap();
+        assert_eq!(error, ParseError::InvalidFileEntry(Location::new(4, 1, "456")));
+
+        let contents = "$ cd /\n$ ls\n12x3 a.txt";
+        let error = get_directories(contents).err().unwrap();
+        assert_eq!(error, ParseError::InvalidFileSize(Location::new(3, 1, "12x3 a.txt")));
+    }
+
+    #[test]
+    fn output_before_the_first_command_is_an_error() {
+        let error = get_directories("hello").err().unwrap();
+        assert_eq!(error, ParseError::OutputBeforeCommand(Location::new(1, 1, "hello")));
+
+        let error = get_directories("123 a.txt\n$ cd /").err().unwrap();
+        assert_eq!(error, ParseError::OutputBeforeCommand(Location::new(1, 1, "123 a.txt")));
+    }
+
+    #[test]
+    fn sizes_beyond_32_bits_are_added_up() {
+        let contents = "$ cd /\n$ ls\n3000000000 a.txt\n$ cd a\n$ ls\n3000000000 b.txt";
+        let root_dir = get_directories(contents).unwrap();
+        assert_eq!(root_dir.get_directory_size(), 6000000000);
+    }
+}
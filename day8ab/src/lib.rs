@@ -0,0 +1,338 @@
+use std::fmt;
+
+use common::diagnostic::Location;
+use grid::{Grid, Point};
+
+pub use grid::Direction;
+
+/// Location of the puzzle input in the source tree, for benchmarks and tests.
+pub const PUZZLE_INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/puzzle_input.txt");
+/// The puzzle input embedded at build time, binaries fall back to it wherever they are installed.
+pub const PUZZLE_INPUT: &str = include_str!("../puzzle_input.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidHeight(Location),
+    RaggedRow(usize, Location),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidHeight(location) => write!(f, "tree height must be a digit at {}", location),
+            ParseError::RaggedRow(expected, location) => write!(f, "expected a row of {} trees at {}", expected, location),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug)]
+pub struct Forest {
+    trees: Grid<i32>,
+}
+
+impl Forest {
+    pub fn new(contents: &str) -> Result<Self, ParseError> {
+        let trees = Grid::parse(contents, |c| c.to_digit(10).map(|height| height as i32))
+            .map_err(|e| match e {
+                grid::ParseError::InvalidCell(location) => ParseError::InvalidHeight(location),
+                grid::ParseError::RaggedRow(expected, location) => ParseError::RaggedRow(expected, location),
+            })?;
+        Ok(Self { trees })
+    }
+
+    pub fn get_visible_trees(&self) -> i32 {
+        self.trees.points()
+            .filter(|p| Direction::ALL.iter().any(|d| self.check_tree_visible_in_direction(p.x, p.y, d)))
+            .count() as i32
+    }
+
+    pub fn get_height_at(&self, x: i32, y: i32) -> i32 {
+        self.trees[Point::new(x, y)]
+    }
+
+    pub fn check_tree_visible_in_direction(&self, x: i32, y: i32, dir: &Direction) -> bool
+    {
+        let position = Point::new(x, y);
+        // trees on the edge count as visible from every direction
+        if self.trees.neighbours4(position).count() < 4 {
+            return true;
+        }
+        let original_height = self.trees[position];
+        self.trees.ray(position, dir.to_vector()).all(|p| self.trees[p] < original_height)
+    }
+
+    pub fn get_view_distance(&self, x: i32, y: i32, dir: &Direction) -> i32
+    {
+        let position = Point::new(x, y);
+        let original_height = self.trees[position];
+        let mut view_distance = 0;
+        for p in self.trees.ray(position, dir.to_vector()) {
+            view_distance += 1;
+            if self.trees[p] >= original_height {
+                break;
+            }
+        }
+        view_distance
+    }
+
+    pub fn calculate_scenic_score_at(&self, x: i32, y: i32) -> i32 {
+        Direction::ALL.
+            iter().
+            map(
+                |d| self.get_view_distance(x, y, d)
+            ).product()
+    }
+
+    pub fn calculate_scenic_score_max(&self) -> i32 {
+        self.trees.points()
+            .map(|p| self.calculate_scenic_score_at(p.x, p.y))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn end_to_end_example() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        let visible_trees = forest.get_visible_trees();
+
+        assert_eq!(visible_trees, 21);
+    }
+
+    #[test]
+    fn end_to_end_one_single_tree() {
+        let contents = "1";
+        let forest = Forest::new(contents).unwrap();
+        let visible_trees = forest.get_visible_trees();
+
+        assert_eq!(visible_trees, 1);
+    }
+
+    #[test]
+    fn forest_constructor_parses_size_y_for_1x1_forest() {
+        let contents = "1";
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.trees.height(), 1);
+    }
+
+    #[test]
+    fn forest_constructor_parses_size_y_for_2x2_forest() {
+        let contents = "12\n34";
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.trees.height(), 2);
+    }
+
+    #[test]
+    fn forest_constructor_parses_size_y_for_3x1_forest() {
+        let contents = "123";
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.trees.height(), 1);
+    }
+
+    #[test]
+    fn forest_constructor_parses_size_y_for_example_forest() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.trees.height(), 5);
+    }
+
+    #[test]
+    fn forest_constructor_parses_size_x_for_1x1_forest() {
+        let contents = "1";
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.trees.width(), 1);
+    }
+
+    #[test]
+    fn forest_constructor_parses_size_x_for_2x2_forest() {
+        let contents = "12\n34";
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.trees.width(), 2);
+    }
+
+    #[test]
+    fn forest_constructor_parses_size_x_for_3x1_forest() {
+        let contents = "123";
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.trees.width(), 3);
+    }
+
+    #[test]
+    fn forest_constructor_parses_size_x_for_example_forest() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.trees.width(), 5);
+    }
+
+
+    #[test]
+    fn forest_get_height_at() {
+        let contents = "12\n34";
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.get_height_at(0, 0), 1);
+        assert_eq!(forest.get_height_at(1, 0), 2);
+        assert_eq!(forest.get_height_at(0, 1), 3);
+        assert_eq!(forest.get_height_at(1, 1), 4);
+    }
+
+    #[test]
+    fn forest_check_tree_visible_in_dir_for_3x3_asc() {
+        let contents = "123\n456\n768";
+        let forest = Forest::new(contents).unwrap();
+        assert!(forest.check_tree_visible_in_direction(1, 1, &Direction::N));
+        assert!(forest.check_tree_visible_in_direction(1, 1, &Direction::W));
+        assert!(!forest.check_tree_visible_in_direction(1, 1, &Direction::S));
+        assert!(!forest.check_tree_visible_in_direction(1, 1, &Direction::E));
+    }
+
+    #[test]
+    fn forest_check_tree_visible_in_dir_for_3x3_center_high() {
+        let contents = "111\n191\n111";
+        let forest = Forest::new(contents).unwrap();
+        assert!(forest.check_tree_visible_in_direction(1, 1, &Direction::N));
+        assert!(forest.check_tree_visible_in_direction(1, 1, &Direction::W));
+        assert!(forest.check_tree_visible_in_direction(1, 1, &Direction::S));
+        assert!(forest.check_tree_visible_in_direction(1, 1, &Direction::E));
+    }
+
+    #[test]
+    fn forest_check_tree_visible_in_dir_for_3x3_center_low() {
+        let contents = "999\n919\n999";
+        let forest = Forest::new(contents).unwrap();
+        assert!(!forest.check_tree_visible_in_direction(1, 1, &Direction::N));
+        assert!(!forest.check_tree_visible_in_direction(1, 1, &Direction::W));
+        assert!(!forest.check_tree_visible_in_direction(1, 1, &Direction::S));
+        assert!(!forest.check_tree_visible_in_direction(1, 1, &Direction::E));
+    }
+
+    #[test]
+    fn forest_check_tree_visible_in_dir_for_1x1() {
+        let contents = "9";
+        let forest = Forest::new(contents).unwrap();
+        assert!(forest.check_tree_visible_in_direction(0, 0, &Direction::N));
+        assert!(forest.check_tree_visible_in_direction(0, 0, &Direction::W));
+        assert!(forest.check_tree_visible_in_direction(0, 0, &Direction::S));
+        assert!(forest.check_tree_visible_in_direction(0, 0, &Direction::E));
+    }
+
+    #[test]
+    fn forest_check_tree_visible_in_dir_for_3x3_on_edge() {
+        let contents = "999\n999\n999";
+        let forest = Forest::new(contents).unwrap();
+        for i in 0..3
+        {
+            for d in &[Direction::N, Direction::S, Direction::E, Direction::W]
+            {
+                assert!(forest.check_tree_visible_in_direction(i, 0, d));
+                assert!(forest.check_tree_visible_in_direction(0, i, d));
+                assert!(forest.check_tree_visible_in_direction(i, 2, d));
+                assert!(forest.check_tree_visible_in_direction(2, i, d));
+            }
+        }
+    }
+
+    #[test]
+    fn forest_check_tree_visible_edge_case() {
+        let contents = "1111\n1111\n1219\n1111";
+        let forest = Forest::new(contents).unwrap();
+
+        assert!(!forest.check_tree_visible_in_direction(1, 2, &Direction::E));
+    }
+
+    #[test]
+    fn forest_check_view_distance_example_1_n() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.get_view_distance(2, 1, &Direction::N), 1);
+    }
+
+    #[test]
+    fn forest_check_view_distance_example_1_w() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.get_view_distance(2, 1, &Direction::W), 1);
+    }
+
+    #[test]
+    fn forest_check_view_distance_example_1_e() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.get_view_distance(2, 1, &Direction::E), 2);
+    }
+
+    #[test]
+    fn forest_check_view_distance_example_1_s() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.get_view_distance(2, 1, &Direction::S), 2);
+    }
+
+
+    #[test]
+    fn forest_check_view_distance_example_2_n() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.get_view_distance(2, 3, &Direction::N), 2);
+    }
+
+    #[test]
+    fn forest_check_view_distance_example_2_w() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.get_view_distance(2, 3, &Direction::W), 2);
+    }
+
+    #[test]
+    fn forest_check_view_distance_example_2_e() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.get_view_distance(2, 3, &Direction::E), 2);
+    }
+
+    #[test]
+    fn forest_check_view_distance_example_2_s() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.get_view_distance(2, 3, &Direction::S), 1);
+    }
+
+    #[test]
+    fn calculate_scenic_score_exaple_1() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.calculate_scenic_score_at(2, 1), 4);
+    }
+
+    #[test]
+    fn calculate_scenic_score_exaple_2() {
+        let contents = include_str!("../example.txt");
+        let forest = Forest::new(contents).unwrap();
+        assert_eq!(forest.calculate_scenic_score_at(2, 3), 8);
+    }
+
+    #[test]
+    fn forest_constructor_rejects_non_digit() {
+        let contents = "123\n4a6";
+        let error = Forest::new(contents).unwrap_err();
+        assert_eq!(error, ParseError::InvalidHeight(Location::new(2, 2, "4a6")));
+    }
+
+    #[test]
+    fn forest_constructor_rejects_ragged_rows() {
+        let contents = "123\n45\n789";
+        let error = Forest::new(contents).unwrap_err();
+        assert_eq!(error, ParseError::RaggedRow(3, Location::new(2, 3, "45")));
+
+        let contents = "123\n4567";
+        let error = Forest::new(contents).unwrap_err();
+        assert_eq!(error, ParseError::RaggedRow(3, Location::new(2, 4, "4567")));
+    }
+}
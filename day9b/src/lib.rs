@@ -0,0 +1,534 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use common::diagnostic::Location;
+use grid::{Direction, Point, Vector};
+
+/// Location of the puzzle input in the source tree, for benchmarks and tests.
+pub const PUZZLE_INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/puzzle_input.txt");
+/// The puzzle input embedded at build time, binaries fall back to it wherever they are installed.
+pub const PUZZLE_INPUT: &str = include_str!("../puzzle_input.txt");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingStepCount(Location),
+    UnknownDirection(Location),
+    InvalidStepCount(Location),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingStepCount(location) => write!(f, "expected a direction and a step count at {}", location),
+            ParseError::UnknownDirection(location) => write!(f, "direction must be one of R, L, U, D at {}", location),
+            ParseError::InvalidStepCount(location) => write!(f, "step count must be a positive number at {}", location),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse_commands(contents: &str) -> Result<Vec<Vector>, ParseError>
+{
+    let mut commands = vec![];
+    let lines = contents.lines().collect::<Vec<_>>();
+    for (i, l) in lines.into_iter().enumerate() {
+        let (dir, count) = l.split_once(" ")
+            .ok_or_else(|| ParseError::MissingStepCount(Location::new(i + 1, l.chars().count() + 1, l)))?;
+        let direction = match dir {
+            "R" => Direction::E,
+            "L" => Direction::W,
+            "U" => Direction::N,
+            "D" => Direction::S,
+            _ => return Err(ParseError::UnknownDirection(Location::of(i + 1, l, dir)))
+        };
+        let count = count.parse::<u32>().map_err(|_| ParseError::InvalidStepCount(Location::of(i + 1, l, count)))?;
+        for _ in 0..count {
+            commands.push(direction.to_vector());
+        }
+    }
+    Ok(commands)
+}
+
+struct Board {
+    knots: Vec<Point>,
+    tail_set: HashSet<Point>,
+}
+
+fn update_single_tail(head: Point, old_tail: Point) -> Point {
+    if old_tail.touches(head) {
+        return old_tail;
+    }
+    old_tail + (head - old_tail).signum()
+}
+
+fn apply_command_to_board(board: &mut Board, command: Vector) {
+    board.knots[0] += command;
+}
+
+fn update_all_tails(board: &mut Board) {
+    for i in 1..board.knots.len()
+    {
+        board.knots[i] = update_single_tail(board.knots[i - 1], board.knots[i]);
+    }
+
+    board.tail_set.insert(*board.knots.last().unwrap());
+}
+
+fn update_board(board: &mut Board, commands: &Vec<Vector>) {
+    board.tail_set.insert(*board.knots.last().unwrap());
+    for c in commands {
+        apply_command_to_board(board, *c);
+        update_all_tails(board);
+    }
+}
+
+pub fn calculate_fields_covered_by_tail(commands: &Vec<Vector>) -> i32
+{
+    let mut board = Board {
+        knots: vec![Point::ORIGIN; 10],
+        tail_set: Default::default(),
+    };
+    update_board(&mut board, commands);
+    board.tail_set.len() as i32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn end_to_end_test_example() {
+        let contents = include_str!("../example.txt");
+        let commands = parse_commands(contents).unwrap();
+        let fields_covered_by_tail = calculate_fields_covered_by_tail(&commands);
+        assert_eq!(fields_covered_by_tail, 1);
+    }
+
+    #[test]
+    fn end_to_end_test_example2_full() {
+        let contents = include_str!("../example2.txt");
+        let commands = parse_commands(contents).unwrap();
+        let fields_covered_by_tail = calculate_fields_covered_by_tail(&commands);
+        assert_eq!(fields_covered_by_tail, 36);
+    }
+
+    #[test]
+    fn end_to_end_test_example2_part1() {
+        let contents = "R 5";
+        let commands = parse_commands(contents).unwrap();
+        let fields_covered_by_tail = calculate_fields_covered_by_tail(&commands);
+        assert_eq!(fields_covered_by_tail, 1);
+    }
+
+    #[test]
+    fn end_to_end_test_example2_part2_fields_covered() {
+        let contents = "R 5\nU 8";
+        let commands = parse_commands(contents).unwrap();
+        let fields_covered_by_tail = calculate_fields_covered_by_tail(&commands);
+        assert_eq!(fields_covered_by_tail, 1);
+    }
+
+    #[test]
+    fn end_to_end_test_example2_part1_positions() {
+        let contents = "R 5";
+        let commands = parse_commands(contents).unwrap();
+
+        let mut board = Board {
+            knots: vec![
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+            ],
+            tail_set: Default::default(),
+        };
+        update_board(&mut board, &commands);
+        assert_eq!(board.knots[0].x, 5);
+        assert_eq!(board.knots[0].y, 0);
+
+        assert_eq!(board.knots[1].x, 4);
+        assert_eq!(board.knots[2].x, 3);
+        assert_eq!(board.knots[3].x, 2);
+        assert_eq!(board.knots[4].x, 1);
+        assert_eq!(board.knots[5].x, 0);
+        assert_eq!(board.knots[6].x, 0);
+        assert_eq!(board.knots[7].x, 0);
+        assert_eq!(board.knots[8].x, 0);
+        assert_eq!(board.knots[9].x, 0);
+    }
+
+    #[test]
+    fn end_to_end_test_example2_part2_positions() {
+        let contents = "R 5\nU 8";
+        let commands = parse_commands(contents).unwrap();
+
+        let mut board = Board {
+            knots: vec![
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+                Point::new(0, 0),
+            ],
+            tail_set: Default::default(),
+        };
+        update_board(&mut board, &commands);
+        assert_eq!(board.knots[0].x, 5);
+        assert_eq!(board.knots[0].y, -8);
+
+        assert_eq!(board.knots[1].x, 5);
+        assert_eq!(board.knots[1].y, -7);
+
+        assert_eq!(board.knots[2].x, 5);
+        assert_eq!(board.knots[2].y, -6);
+
+        assert_eq!(board.knots[3].x, 5);
+        assert_eq!(board.knots[3].y, -5);
+
+        assert_eq!(board.knots[4].x, 5);
+        assert_eq!(board.knots[4].y, -4);
+
+        assert_eq!(board.knots[5].x, 4);
+        assert_eq!(board.knots[5].y, -4);
+
+        assert_eq!(board.knots[6].x, 3);
+        assert_eq!(board.knots[6].y, -3);
+
+        assert_eq!(board.knots[7].x, 2);
+        assert_eq!(board.knots[7].y, -2);
+
+        assert_eq!(board.knots[8].x, 1);
+        assert_eq!(board.knots[8].y, -1);
+
+        assert_eq!(board.knots[9].x, 0);
+        assert_eq!(board.knots[9].y, 0);
+    }
+
+    #[test]
+    fn parse_single_command_r1() {
+        let contents = "R 1";
+        let commands = parse_commands(contents).unwrap();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].x, 1);
+        assert_eq!(commands[0].y, 0);
+    }
+
+    #[test]
+    fn parse_single_command_l1() {
+        let contents = "L 1";
+        let commands = parse_commands(contents).unwrap();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].x, -1);
+        assert_eq!(commands[0].y, 0);
+    }
+
+    #[test]
+    fn parse_single_command_u1() {
+        let contents = "U 1";
+        let commands = parse_commands(contents).unwrap();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].x, 0);
+        assert_eq!(commands[0].y, -1);
+    }
+
+    #[test]
+    fn parse_single_command_d1() {
+        let contents = "D 1";
+        let commands = parse_commands(contents).unwrap();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].x, 0);
+        assert_eq!(commands[0].y, 1);
+    }
+
+    #[test]
+    fn parse_single_command_d2() {
+        let contents = "D 2";
+        let commands = parse_commands(contents).unwrap();
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].x, 0);
+        assert_eq!(commands[0].y, 1);
+        assert_eq!(commands[1].x, 0);
+        assert_eq!(commands[1].y, 1);
+    }
+
+
+    #[test]
+    fn update_board_single_step_moves_head_north() {
+        let mut b = Board { knots: vec![Point::new(0, 0)], tail_set: HashSet::new() };
+        let command = Vector::new(0, -1);
+        apply_command_to_board(&mut b, command);
+        assert_eq!(b.knots[0].x, 0);
+        assert_eq!(b.knots[0].y, -1);
+    }
+
+    #[test]
+    fn update_board_single_step_moves_head_south() {
+        let mut b = Board { knots: vec![Point::new(0, 0)], tail_set: HashSet::new() };
+        let command = Vector::new(0, 1);
+        apply_command_to_board(&mut b, command);
+        assert_eq!(b.knots[0].x, 0);
+        assert_eq!(b.knots[0].y, 1);
+    }
+
+    #[test]
+    fn update_board_single_step_moves_head_east() {
+        let mut b = Board { knots: vec![Point::new(0, 0)], tail_set: HashSet::new() };
+        let command = Vector::new(1, 0);
+        apply_command_to_board(&mut b, command);
+        assert_eq!(b.knots[0].x, 1);
+        assert_eq!(b.knots[0].y, 0);
+    }
+
+    #[test]
+    fn update_board_single_step_moves_head_west() {
+        let mut b = Board { knots: vec![Point::new(0, 0)], tail_set: HashSet::new() };
+        let command = Vector::new(-1, 0);
+        apply_command_to_board(&mut b, command);
+        assert_eq!(b.knots[0].x, -1);
+        assert_eq!(b.knots[0].y, 0);
+    }
+
+    #[test]
+    fn update_board_example_moves_head_correctly() {
+        let contents = include_str!("../example.txt");
+        let commands = parse_commands(contents).unwrap();
+
+        let mut b = Board { knots: vec![Point::new(0, 0)], tail_set: HashSet::new() };
+        update_board(&mut b, &commands);
+
+        assert_eq!(b.knots[0].x, 2);
+        assert_eq!(b.knots[0].y, -2);
+    }
+
+    #[test]
+    fn update_board_example_moves_tail_correctly() {
+        let contents = include_str!("../example.txt");
+        let commands = parse_commands(contents).unwrap();
+
+        let mut b = Board { knots: vec![Point::new(0, 0), Point::new(0, 0), Point::new(0, 0), Point::new(0, 0), Point::new(0, 0), Point::new(0, 0), Point::new(0, 0), Point::new(0, 0), Point::new(0, 0), Point::new(0, 0)], tail_set: HashSet::new() };
+        update_board(&mut b, &commands);
+
+        assert_eq!(b.knots.last().unwrap().x, 0);
+        assert_eq!(b.knots.last().unwrap().y, 0);
+    }
+
+    #[test]
+    fn update_tail_on_same_pos() {
+        let head = Point::new(0, 0);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 0);
+        assert_eq!(new_tail.y, 0);
+    }
+
+    #[test]
+    fn update_tail_one_north_does_not_move_tail() {
+        let head = Point::new(0, -1);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 0);
+        assert_eq!(new_tail.y, 0);
+    }
+
+    #[test]
+    fn update_tail_one_northeast_does_not_move_tail() {
+        let head = Point::new(1, -1);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 0);
+        assert_eq!(new_tail.y, 0);
+    }
+
+    #[test]
+    fn update_tail_one_east_does_not_move_tail() {
+        let head = Point::new(1, 0);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 0);
+        assert_eq!(new_tail.y, 0);
+    }
+
+    #[test]
+    fn update_tail_one_southeast_does_not_move_tail() {
+        let head = Point::new(1, 1);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 0);
+        assert_eq!(new_tail.y, 0);
+    }
+
+    #[test]
+    fn update_tail_one_south_does_not_move_tail() {
+        let head = Point::new(0, 1);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 0);
+        assert_eq!(new_tail.y, 0);
+    }
+
+    #[test]
+    fn update_tail_one_southwest_does_not_move_tail() {
+        let head = Point::new(-1, 1);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 0);
+        assert_eq!(new_tail.y, 0);
+    }
+
+    #[test]
+    fn update_tail_one_west_does_not_move_tail() {
+        let head = Point::new(-1, 0);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 0);
+        assert_eq!(new_tail.y, 0);
+    }
+
+    #[test]
+    fn update_tail_one_northwest_does_not_move_tail() {
+        let head = Point::new(-1, -1);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 0);
+        assert_eq!(new_tail.y, 0);
+    }
+
+    #[test]
+    fn update_tail_two_north_does_move_tail() {
+        let head = Point::new(0, -2);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 0);
+        assert_eq!(new_tail.y, -1);
+    }
+
+    #[test]
+    fn update_tail_two_south_does_move_tail() {
+        let head = Point::new(0, 2);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 0);
+        assert_eq!(new_tail.y, 1);
+    }
+
+    #[test]
+    fn update_tail_two_east_does_move_tail() {
+        let head = Point::new(2, 0);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 1);
+        assert_eq!(new_tail.y, 0);
+    }
+
+    #[test]
+    fn update_tail_two_west_does_move_tail() {
+        let head = Point::new(-2, 0);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, -1);
+        assert_eq!(new_tail.y, 0);
+    }
+
+    #[test]
+    fn update_tail_two_north_one_east() {
+        let head = Point::new(1, -2);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 1);
+        assert_eq!(new_tail.y, -1);
+    }
+
+    #[test]
+    fn update_tail_two_south_one_east() {
+        let head = Point::new(1, 2);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 1);
+        assert_eq!(new_tail.y, 1);
+    }
+
+    #[test]
+    fn update_tail_two_north_one_west() {
+        let head = Point::new(-1, -2);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, -1);
+        assert_eq!(new_tail.y, -1);
+    }
+
+    #[test]
+    fn update_tail_one_south_two_east() {
+        let head = Point::new(2, 1);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, 1);
+        assert_eq!(new_tail.y, 1);
+    }
+
+    #[test]
+    fn update_tail_one_north_two_west() {
+        let head = Point::new(-2, -1);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, -1);
+        assert_eq!(new_tail.y, -1);
+    }
+
+    #[test]
+    fn update_tail_two_south_one_west() {
+        let head = Point::new(-1, 2);
+        let tail = Point::new(0, 0);
+        let new_tail = update_single_tail(head, tail);
+
+        assert_eq!(new_tail.x, -1);
+        assert_eq!(new_tail.y, 1);
+    }
+
+    #[test]
+    fn parse_missing_step_count_is_an_error() {
+        let error = parse_commands("R 1\nU").unwrap_err();
+        assert_eq!(error, ParseError::MissingStepCount(Location::new(2, 2, "U")));
+    }
+
+    #[test]
+    fn parse_unknown_direction_is_an_error() {
+        let error = parse_commands("X 1").unwrap_err();
+        assert_eq!(error, ParseError::UnknownDirection(Location::new(1, 1, "X 1")));
+    }
+
+    #[test]
+    fn parse_invalid_step_count_is_an_error() {
+        let error = parse_commands("R -1").unwrap_err();
+        assert_eq!(error, ParseError::InvalidStepCount(Location::new(1, 3, "R -1")));
+    }
+}
@@ -0,0 +1,205 @@
+//! Rectangular grids of cells addressed by [`Point`]s, as used by the map based puzzles.
+
+mod point;
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use common::diagnostic::Location;
+
+pub use point::{Direction, Point, Vector};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidCell(Location),
+    RaggedRow(usize, Location),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidCell(location) => write!(f, "unexpected character at {}", location),
+            ParseError::RaggedRow(expected, location) => write!(f, "expected a row of {} cells at {}", expected, location),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self { cells: vec![fill; width * height], width, height }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map with one row per line, `cell` converts a character or rejects it with `None`.
+    pub fn parse(contents: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let lines = contents.lines().collect::<Vec<_>>();
+        let width = if lines.is_empty() { 0 } else { lines[0].chars().count() };
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            if line.chars().count() != width {
+                let column = line.chars().count().min(width) + 1;
+                return Err(ParseError::RaggedRow(width, Location::new(i + 1, column, line)));
+            }
+            for (j, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or_else(|| ParseError::InvalidCell(Location::new(i + 1, j + 1, line)))?);
+            }
+        }
+        Ok(Self { cells, width, height: lines.len() })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x >= 0 && p.y >= 0 && (p.x as usize) < self.width && (p.y as usize) < self.height
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        if !self.contains(p) {
+            return None;
+        }
+        Some(p.x as usize + p.y as usize * self.width)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item=Point> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Points visited when walking from `start` in steps of `step` until the edge, `start` itself excluded.
+    /// A zero `step` never leaves `start`, so its ray is empty.
+    pub fn ray(&self, start: Point, step: Vector) -> impl Iterator<Item=Point> + '_ {
+        std::iter::successors(Some(start + step), move |p| Some(*p + step))
+            .take_while(move |p| step != Vector::ZERO && self.contains(*p))
+    }
+
+    /// The 4-neighbourhood of `p` inside the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item=Point> + '_ {
+        p.neighbours4().filter(move |n| self.contains(*n))
+    }
+
+    /// The 8-neighbourhood of `p` inside the grid.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item=Point> + '_ {
+        p.neighbours8().filter(move |n| self.contains(*n))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.index_of(p) {
+            Some(i) => &self.cells[i],
+            None => panic!("{:?} is outside of the {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.index_of(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{:?} is outside of the {}x{} grid", p, width, height),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn digits(contents: &str) -> Grid<u32> {
+        Grid::parse(contents, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parse_character_map() {
+        let grid = digits("123\n456");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(0, 0)], 1);
+        assert_eq!(grid[Point::new(2, 1)], 6);
+    }
+
+    #[test]
+    fn parse_rejects_invalid_cells_and_ragged_rows() {
+        let error = Grid::parse("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(error, ParseError::InvalidCell(Location::new(2, 2, "3x")));
+        let error = Grid::parse("12\n3", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(error, ParseError::RaggedRow(2, Location::new(2, 2, "3")));
+    }
+
+    #[test]
+    fn get_is_bounds_checked() {
+        let grid = digits("12\n34");
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&4));
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the 2x2 grid")]
+    fn index_outside_panics() {
+        let grid = digits("12\n34");
+        let _ = grid[Point::new(0, 2)];
+    }
+
+    #[test]
+    fn index_mut_updates_cell() {
+        let mut grid = Grid::new(2, 2, '.');
+        grid[Point::new(1, 0)] = '#';
+        assert_eq!(grid.get(Point::new(1, 0)), Some(&'#'));
+        *grid.get_mut(Point::new(0, 1)).unwrap() = '#';
+        assert_eq!(grid.points().filter(|p| grid[*p] == '#').count(), 2);
+    }
+
+    #[test]
+    fn points_are_listed_row_by_row() {
+        let grid = digits("12\n34");
+        let points: Vec<Point> = grid.points().collect();
+        assert_eq!(points, vec![Point::new(0, 0), Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)]);
+    }
+
+    #[test]
+    fn ray_walks_until_edge() {
+        let grid = digits("123\n456\n789");
+        let values: Vec<u32> = grid.ray(Point::new(0, 1), Vector::E).map(|p| grid[p]).collect();
+        assert_eq!(values, vec![5, 6]);
+        let values: Vec<u32> = grid.ray(Point::new(0, 0), Vector::SE).map(|p| grid[p]).collect();
+        assert_eq!(values, vec![5, 9]);
+        assert_eq!(grid.ray(Point::new(1, 0), Vector::N).count(), 0);
+        assert_eq!(grid.ray(Point::new(1, 1), Vector::ZERO).count(), 0);
+    }
+
+    #[test]
+    fn neighbours_stay_inside_grid() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(grid.neighbours4(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+    }
+}
@@ -0,0 +1,170 @@
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+/// Offset between two points. `y` grows downwards, like the rows of a character map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector::new(0, 0);
+    pub const N: Vector = Vector::new(0, -1);
+    pub const S: Vector = Vector::new(0, 1);
+    pub const E: Vector = Vector::new(1, 0);
+    pub const W: Vector = Vector::new(-1, 0);
+    pub const NE: Vector = Vector::new(1, -1);
+    pub const NW: Vector = Vector::new(-1, -1);
+    pub const SE: Vector = Vector::new(1, 1);
+    pub const SW: Vector = Vector::new(-1, 1);
+
+    /// Steps to the 4-neighbourhood.
+    pub const ORTHOGONAL: [Vector; 4] = [Vector::N, Vector::E, Vector::S, Vector::W];
+    /// Steps to the 8-neighbourhood.
+    pub const ADJACENT: [Vector; 8] = [
+        Vector::N, Vector::NE, Vector::E, Vector::SE, Vector::S, Vector::SW, Vector::W, Vector::NW,
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Unit step in the direction of the vector, each component is -1, 0 or 1.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Number of king moves needed to cover the vector.
+    pub fn chebyshev_length(self) -> i32 {
+        self.x.abs().max(self.y.abs())
+    }
+
+    pub fn manhattan_length(self) -> i32 {
+        self.x.abs() + self.y.abs()
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Mul<i32> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: i32) -> Vector {
+        Vector::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// Position on a grid, `(0, 0)` is the top left corner of a character map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item=Point> {
+        Vector::ORTHOGONAL.into_iter().map(move |v| self + v)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item=Point> {
+        Vector::ADJACENT.into_iter().map(move |v| self + v)
+    }
+
+    /// Whether the points are equal or neighbours in the 8-neighbourhood.
+    pub fn touches(self, other: Point) -> bool {
+        (other - self).chebyshev_length() <= 1
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vector) -> Point {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        self.x += v.x;
+        self.y += v.y;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// The four directions along rows and columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    N,
+    S,
+    E,
+    W,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::N, Direction::S, Direction::E, Direction::W];
+
+    pub fn to_vector(self) -> Vector {
+        match self {
+            Direction::N => Vector::N,
+            Direction::S => Vector::S,
+            Direction::E => Vector::E,
+            Direction::W => Vector::W,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn point_arithmetic() {
+        let p = Point::new(2, 3);
+        assert_eq!(p + Vector::N, Point::new(2, 2));
+        assert_eq!(p - Point::new(5, 1), Vector::new(-3, 2));
+        assert_eq!(Vector::SE * 3, Vector::new(3, 3));
+    }
+
+    #[test]
+    fn vector_lengths_and_signum() {
+        let v = Vector::new(-3, 2);
+        assert_eq!(v.signum(), Vector::SW);
+        assert_eq!(v.chebyshev_length(), 3);
+        assert_eq!(v.manhattan_length(), 5);
+    }
+
+    #[test]
+    fn neighbourhoods() {
+        let neighbours: Vec<Point> = Point::ORIGIN.neighbours4().collect();
+        assert_eq!(neighbours, vec![Point::new(0, -1), Point::new(1, 0), Point::new(0, 1), Point::new(-1, 0)]);
+        assert_eq!(Point::ORIGIN.neighbours8().count(), 8);
+        assert!(Point::ORIGIN.neighbours8().all(|p| p.touches(Point::ORIGIN)));
+        assert!(!Point::ORIGIN.touches(Point::new(2, 1)));
+    }
+
+    #[test]
+    fn direction_to_vector() {
+        assert_eq!(Direction::N.to_vector(), Vector::new(0, -1));
+        assert_eq!(Direction::E.to_vector(), Vector::new(1, 0));
+    }
+}
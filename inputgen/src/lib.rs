@@ -0,0 +1,295 @@
+//! Seeded generators of valid puzzle inputs of any size, for stress tests.
+//!
+//! The same day, seed and size always produce the same input.
+
+pub use common::rng::Rng;
+
+/// A generator with the meaning of its `size` parameter.
+pub struct Generator {
+    pub day: u32,
+    pub size_unit: &'static str,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+pub const GENERATORS: [Generator; 9] = [
+    Generator { day: 1, size_unit: "elves", generate: calorie_groups },
+    Generator { day: 2, size_unit: "rounds", generate: strategy_guide },
+    Generator { day: 3, size_unit: "groups of three rucksacks", generate: rucksacks },
+    Generator { day: 4, size_unit: "pairs", generate: section_pairs },
+    Generator { day: 5, size_unit: "moves", generate: crate_moves },
+    Generator { day: 6, size_unit: "characters", generate: signal_stream },
+    Generator { day: 7, size_unit: "directories", generate: terminal_transcript },
+    Generator { day: 8, size_unit: "trees per side", generate: tree_heights },
+    Generator { day: 9, size_unit: "motions", generate: rope_motions },
+];
+
+pub fn find_generator(day: u32) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+const ITEM_TYPES: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const CRATE_LABELS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Groups of 1 to 10 calorie counts, small enough that no elf's total overflows.
+pub fn calorie_groups(rng: &mut Rng, elves: usize) -> String {
+    let groups: Vec<String> = (0..elves)
+        .map(|_| {
+            let items = rng.range(1, 10);
+            let calories: Vec<String> = (0..items).map(|_| rng.range(1000, 60000).to_string()).collect();
+            calories.join("\n")
+        })
+        .collect();
+    groups.join("\n\n")
+}
+
+pub fn strategy_guide(rng: &mut Rng, rounds: usize) -> String {
+    let lines: Vec<String> = (0..rounds)
+        .map(|_| format!("{} {}", rng.pick(&['A', 'B', 'C']), rng.pick(&['X', 'Y', 'Z'])))
+        .collect();
+    lines.join("\n")
+}
+
+/// Fills a compartment with `len` items from `pool`, containing every item of `required`.
+fn compartment(rng: &mut Rng, len: usize, pool: &[char], required: &[char]) -> Vec<char> {
+    let mut items: Vec<char> = required.to_vec();
+    while items.len() < len {
+        items.push(rng.pick(pool));
+    }
+    rng.shuffle(&mut items);
+    items
+}
+
+/// Rucksacks that share exactly one item type between their compartments,
+/// in groups of three that have exactly one item type, the badge, in common.
+pub fn rucksacks(rng: &mut Rng, groups: usize) -> String {
+    let mut lines = vec![];
+    for _ in 0..groups {
+        let mut item_types: Vec<char> = ITEM_TYPES.chars().collect();
+        rng.shuffle(&mut item_types);
+        let badge = item_types[0];
+        // every rucksack of the group draws from its own 17 item types, so only the badge is common to all
+        for own_types in item_types[1..].chunks(17) {
+            let shared = own_types[0];
+            let (left_pool, right_pool) = own_types[1..].split_at(8);
+            let badge_on_left = rng.range(0, 1) == 0;
+            let len = rng.range(2, 16) as usize;
+
+            let left_required = if badge_on_left { vec![shared, badge] } else { vec![shared] };
+            let right_required = if badge_on_left { vec![shared] } else { vec![shared, badge] };
+            let mut rucksack = compartment(rng, len, left_pool, &left_required);
+            rucksack.extend(compartment(rng, len, right_pool, &right_required));
+            lines.push(rucksack.into_iter().collect::<String>());
+        }
+    }
+    lines.join("\n")
+}
+
+pub fn section_pairs(rng: &mut Rng, pairs: usize) -> String {
+    let range = |rng: &mut Rng| {
+        let start = rng.range(1, 99);
+        format!("{}-{}", start, rng.range(start, 99))
+    };
+    let lines: Vec<String> = (0..pairs).map(|_| format!("{},{}", range(rng), range(rng))).collect();
+    lines.join("\n")
+}
+
+fn draw_stacks(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+    let mut rows = vec![];
+    for level in (0..height).rev() {
+        let cells: Vec<String> = stacks.iter()
+            .map(|s| s.get(level).map_or(String::from("   "), |c| format!("[{}]", c)))
+            .collect();
+        rows.push(cells.join(" "));
+    }
+    let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!(" {} ", n)).collect();
+    rows.push(numbers.join(" "));
+    rows.join("\n")
+}
+
+/// A drawing of 2 to 9 stacks and moves that never take more crates from a stack than it holds,
+/// for both crane models.
+pub fn crate_moves(rng: &mut Rng, moves: usize) -> String {
+    let labels: Vec<char> = CRATE_LABELS.chars().collect();
+    let stack_count = rng.range(2, 9) as usize;
+    let mut stacks: Vec<Vec<char>> = (0..stack_count)
+        .map(|_| (0..rng.range(0, 8)).map(|_| rng.pick(&labels)).collect())
+        .collect();
+    if stacks.iter().all(|s| s.is_empty()) {
+        stacks[0].push(rng.pick(&labels));
+    }
+    let drawing = draw_stacks(&stacks);
+
+    let mut lines = vec![];
+    for _ in 0..moves {
+        let non_empty: Vec<usize> = (0..stack_count).filter(|i| !stacks[*i].is_empty()).collect();
+        let src = rng.pick(&non_empty);
+        let dest = (src + rng.range(1, stack_count as u64 - 1) as usize) % stack_count;
+        let count = rng.range(1, stacks[src].len() as u64) as usize;
+        let remaining = stacks[src].len() - count;
+        let moved = stacks[src].split_off(remaining);
+        stacks[dest].extend(moved);
+        lines.push(format!("move {} from {} to {}", count, src + 1, dest + 1));
+    }
+    format!("{}\n\n{}", drawing, lines.join("\n"))
+}
+
+/// A stream that contains 14 distinct characters at a random position.
+/// Everything before is made of three other letters, so the first marker of either part ends inside the planted characters.
+pub fn signal_stream(rng: &mut Rng, len: usize) -> String {
+    let len = len.max(14);
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut letters);
+    let (marker, filler) = letters.split_at(14);
+    let filler = &filler[..3];
+
+    let marker_start = rng.index(len - 13);
+    let mut stream: String = (0..marker_start).map(|_| rng.pick(filler)).collect();
+    stream.extend(marker.iter());
+    stream.extend((marker_start + 14..len).map(|_| rng.pick(&letters)));
+    stream
+}
+
+/// Lists the content of directory `dir` and descends into its sub directories.
+fn explore(rng: &mut Rng, children: &[Vec<usize>], dir: usize, lines: &mut Vec<String>) {
+    lines.push(String::from("$ ls"));
+    for i in 0..rng.range(0, 4) {
+        lines.push(format!("{} file{}.txt", rng.range(1, 300000), i));
+    }
+    for child in &children[dir] {
+        lines.push(format!("dir d{}", child));
+    }
+    for child in &children[dir] {
+        lines.push(format!("$ cd d{}", child));
+        explore(rng, children, *child, lines);
+        lines.push(String::from("$ cd .."));
+    }
+}
+
+/// `$ cd` and `$ ls` output for a random tree of `directories` directories below `/`.
+pub fn terminal_transcript(rng: &mut Rng, directories: usize) -> String {
+    // directory 0 is the root, every further directory is attached to a random earlier one
+    let mut children: Vec<Vec<usize>> = vec![vec![]];
+    for dir in 1..=directories {
+        let parent = rng.index(dir);
+        children[parent].push(dir);
+        children.push(vec![]);
+    }
+    let mut lines = vec![String::from("$ cd /")];
+    explore(rng, &children, 0, &mut lines);
+    lines.join("\n")
+}
+
+/// A square forest with `side` trees per row and column.
+pub fn tree_heights(rng: &mut Rng, side: usize) -> String {
+    let rows: Vec<String> = (0..side)
+        .map(|_| (0..side).map(|_| char::from(b'0' + rng.range(0, 9) as u8)).collect())
+        .collect();
+    rows.join("\n")
+}
+
+pub fn rope_motions(rng: &mut Rng, motions: usize) -> String {
+    let lines: Vec<String> = (0..motions)
+        .map(|_| format!("{} {}", rng.pick(&['R', 'L', 'U', 'D']), rng.range(1, 20)))
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn generate(day: u32, seed: u64, size: usize) -> String {
+        (find_generator(day).unwrap().generate)(&mut Rng::new(seed), size)
+    }
+
+    fn item_types(items: &str) -> HashSet<char> {
+        items.chars().collect()
+    }
+
+    #[test]
+    fn generators_are_deterministic() {
+        for generator in &GENERATORS {
+            assert_eq!(generate(generator.day, 42, 50), generate(generator.day, 42, 50), "day {}", generator.day);
+            assert_ne!(generate(generator.day, 42, 50), generate(generator.day, 43, 50), "day {}", generator.day);
+        }
+    }
+
+    #[test]
+    fn calorie_groups_parse() {
+        let calories = day1::get_calories_per_elf(&generate(1, 1, 200)).unwrap();
+        assert_eq!(calories.len(), 200);
+    }
+
+    #[test]
+    fn strategy_guide_parses() {
+        for strategy in day2::Strategy::ALL {
+            assert!(day2::calculate_total_score(&generate(2, 1, 500), strategy).is_ok());
+        }
+    }
+
+    #[test]
+    fn rucksacks_have_one_shared_item_and_one_badge_per_group() {
+        let contents = generate(3, 1, 100);
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 300);
+        for line in &lines {
+            let (left, right) = line.split_at(line.len() / 2);
+            assert_eq!(item_types(left).intersection(&item_types(right)).count(), 1, "{}", line);
+        }
+        for group in lines.chunks(3) {
+            let common: HashSet<char> = item_types(group[0]).intersection(&item_types(group[1])).copied().collect();
+            assert_eq!(common.intersection(&item_types(group[2])).count(), 1, "{:?}", group);
+        }
+        assert!(day3::calculate_compartment_sum(&contents).is_ok());
+        assert!(day3::calculate_badge_sum(&contents).is_ok());
+    }
+
+    #[test]
+    fn section_pairs_parse() {
+        assert_eq!(day4::parse_elves_pairs(&generate(4, 1, 300)).unwrap().len(), 300);
+    }
+
+    #[test]
+    fn crate_moves_never_take_from_empty_stacks() {
+        for (seed, size) in (0..20).flat_map(|seed| [(seed, 50), (seed, 300)]) {
+            let contents = generate(5, seed, size);
+            let mut stacks_9000 = day5a::Stacks::new(&contents).unwrap();
+            stacks_9000.apply_moves().unwrap();
+            let mut stacks_9001 = day5b::Stacks::new(&contents).unwrap();
+            stacks_9001.apply_moves().unwrap();
+            // stacks may end up empty, they have no top crate
+            assert!(stacks_9000.get_top_crates().len() <= 9 && stacks_9001.get_top_crates().len() <= 9);
+        }
+    }
+
+    #[test]
+    fn signal_stream_contains_planted_marker() {
+        for seed in 0..20 {
+            let stream = generate(6, seed, 1000);
+            assert_eq!(stream.len(), 1000);
+            let position = day6b::find_start_signal_position(&stream).unwrap();
+            assert_eq!(item_types(&stream[position - 14..position]).len(), 14);
+        }
+    }
+
+    #[test]
+    fn terminal_transcript_has_requested_directories() {
+        let contents = generate(7, 1, 500);
+        assert_eq!(contents.lines().filter(|l| l.starts_with("dir ")).count(), 500);
+        assert!(day7b::get_directories(&contents).is_ok());
+    }
+
+    #[test]
+    fn tree_heights_parse() {
+        let forest = day8ab::Forest::new(&generate(8, 1, 30)).unwrap();
+        assert!(forest.get_visible_trees() >= 4 * 29);
+    }
+
+    #[test]
+    fn rope_motions_parse() {
+        assert!(!day9b::parse_commands(&generate(9, 1, 100)).unwrap().is_empty());
+    }
+}
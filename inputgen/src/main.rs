@@ -0,0 +1,47 @@
+use std::process::ExitCode;
+
+use inputgen::{find_generator, Rng, GENERATORS};
+
+const USAGE: &str = "usage: inputgen <day> [--seed <n>] [--size <n>]";
+
+fn parse_number<T: std::str::FromStr>(value: Option<String>, what: &str) -> Result<T, String> {
+    let value = value.ok_or(format!("missing value for {}", what))?;
+    value.parse::<T>().map_err(|_| format!("invalid {}: '{}'", what, value))
+}
+
+fn run(mut args: impl Iterator<Item=String>) -> Result<String, String> {
+    let mut day = None;
+    let mut seed = 0;
+    let mut size = 1000;
+    while let Some(arg) = args.next() {
+        if arg == "--seed" || arg == "-s" {
+            seed = parse_number(args.next(), "seed")?;
+        } else if arg == "--size" || arg == "-n" {
+            size = parse_number(args.next(), "size")?;
+        } else if day.is_none() {
+            day = Some(parse_number::<u32>(Some(arg), "day")?);
+        } else {
+            return Err(format!("unexpected argument '{}'", arg));
+        }
+    }
+
+    let day = day.ok_or("missing day")?;
+    let generator = find_generator(day).ok_or(format!("no generator for day {}", day))?;
+    Ok((generator.generate)(&mut Rng::new(seed), size))
+}
+
+fn main() -> ExitCode {
+    match run(std::env::args().skip(1)) {
+        Ok(input) => {
+            println!("{}", input);
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            for generator in &GENERATORS {
+                eprintln!("  day {}: size is the number of {}", generator.day, generator.size_unit);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}